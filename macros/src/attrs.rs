@@ -0,0 +1,890 @@
+use crate::dynamo::attribute_value::AttributeValueType;
+use crate::tags::{
+    AWS_DYNAMO_ATTR_META_ENTRY, KEY_ALLOW, KEY_ATTRIBUTE_TYPE, KEY_BILLING_MODE,
+    KEY_DELETION_PROTECTION, KEY_DENY_UNKNOWN_ATTRIBUTES, KEY_EMPTY_SETS,
+    KEY_GLOBAL_SECONDARY_INDEX, KEY_INDEX_NAME, KEY_KMS_MASTER_KEY_ID, KEY_LENIENT_NUMBERS,
+    KEY_LOCAL_SECONDARY_INDEX, KEY_MAX_READ_REQUEST_UNITS, KEY_MAX_WRITE_REQUEST_UNITS,
+    KEY_NON_KEY_ATTRIBUTES, KEY_ON_DEMAND_THROUGHPUT, KEY_OTHER, KEY_POINT_IN_TIME_RECOVERY,
+    KEY_PROJECTION, KEY_PROVISIONED_THROUGHPUT, KEY_READ_CAPACITY_UNITS, KEY_SSE, KEY_STREAM,
+    KEY_TABLE_CLASS, KEY_TABLE_NAME, KEY_WITH, KEY_WRITE_CAPACITY_UNITS,
+};
+use crate::util::did_you_mean;
+
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    bracketed, Attribute, Error, Expr, ExprLit, Lit, LitInt, LitStr, Meta, Path, Result, Token,
+};
+
+/// keys available on top of the struct deriving `Table`
+pub const TABLE_STRUCT_KEYS: &[&str] = &[
+    KEY_TABLE_NAME,
+    KEY_DENY_UNKNOWN_ATTRIBUTES,
+    KEY_LENIENT_NUMBERS,
+    KEY_EMPTY_SETS,
+    KEY_LOCAL_SECONDARY_INDEX,
+    KEY_GLOBAL_SECONDARY_INDEX,
+    KEY_BILLING_MODE,
+    KEY_PROVISIONED_THROUGHPUT,
+    KEY_ON_DEMAND_THROUGHPUT,
+    KEY_STREAM,
+    KEY_TABLE_CLASS,
+    KEY_DELETION_PROTECTION,
+    KEY_SSE,
+    KEY_POINT_IN_TIME_RECOVERY,
+];
+/// keys available in `local_secondary_index(..)` on top of the struct
+const LOCAL_INDEX_KEYS: &[&str] = &[KEY_INDEX_NAME, KEY_PROJECTION, KEY_NON_KEY_ATTRIBUTES];
+/// keys available in `global_secondary_index(..)` on top of the struct
+const GLOBAL_INDEX_KEYS: &[&str] = &[
+    KEY_INDEX_NAME,
+    KEY_PROJECTION,
+    KEY_NON_KEY_ATTRIBUTES,
+    KEY_PROVISIONED_THROUGHPUT,
+    KEY_ON_DEMAND_THROUGHPUT,
+];
+const PROVISIONED_THROUGHPUT_KEYS: &[&str] = &[KEY_READ_CAPACITY_UNITS, KEY_WRITE_CAPACITY_UNITS];
+const ON_DEMAND_THROUGHPUT_KEYS: &[&str] =
+    &[KEY_MAX_READ_REQUEST_UNITS, KEY_MAX_WRITE_REQUEST_UNITS];
+/// keys available on top of the struct deriving `Item`
+pub const ITEM_STRUCT_KEYS: &[&str] = &[
+    KEY_DENY_UNKNOWN_ATTRIBUTES,
+    KEY_LENIENT_NUMBERS,
+    KEY_EMPTY_SETS,
+];
+/// keys available on fields of the struct deriving `Item`
+pub const ITEM_FIELD_KEYS: &[&str] = &[KEY_OTHER, KEY_WITH];
+
+/// attributes annotated on top of the struct
+#[derive(Clone, Debug, Default)]
+pub struct StructAttrs {
+    /// `#[aws_dynamo(table_name = "..")]`
+    pub table_name: Option<LitStr>,
+    /// `#[aws_dynamo(deny_unknown_attributes(allow = [..]))]`, holding tolerated attribute names
+    pub deny_unknown_attributes: Option<Vec<LitStr>>,
+    /// `#[aws_dynamo(lenient_numbers)]`, reading numbers stored as S as well
+    pub lenient_numbers: bool,
+    /// `#[aws_dynamo(empty_sets = "..")]`
+    pub empty_sets: Option<EmptySets>,
+    /// `#[aws_dynamo(local_secondary_index(index_name = "..", ..))]`
+    pub local_secondary_indexes: Vec<IndexAttrs>,
+    /// `#[aws_dynamo(global_secondary_index(index_name = "..", ..))]`
+    pub global_secondary_indexes: Vec<IndexAttrs>,
+    /// `#[aws_dynamo(billing_mode = "..")]`, with the span of its value
+    pub billing_mode: Option<(BillingMode, Span)>,
+    /// `#[aws_dynamo(provisioned_throughput(..))]`
+    pub provisioned_throughput: Option<ProvisionedThroughput>,
+    /// `#[aws_dynamo(on_demand_throughput(..))]`
+    pub on_demand_throughput: Option<OnDemandThroughput>,
+    /// `#[aws_dynamo(stream = "..")]`
+    pub stream: Option<StreamViewType>,
+    /// `#[aws_dynamo(table_class = "..")]`
+    pub table_class: Option<TableClass>,
+    /// `#[aws_dynamo(deletion_protection)]`
+    pub deletion_protection: bool,
+    /// `#[aws_dynamo(sse)]` or `#[aws_dynamo(sse(kms_master_key_id = ".."))]`
+    pub sse: Option<Sse>,
+    /// `#[aws_dynamo(point_in_time_recovery)]`
+    pub point_in_time_recovery: bool,
+}
+
+/// server-side encryption with a KMS key, the AWS managed one unless `kms_master_key_id` is given
+#[derive(Clone, Debug)]
+pub struct Sse {
+    pub kms_master_key_id: Option<LitStr>,
+}
+
+/// settings of a secondary index declared on top of the struct, whose keys are declared on fields
+#[derive(Clone, Debug)]
+pub struct IndexAttrs {
+    /// `index_name = ".."`
+    pub index_name: LitStr,
+    /// `projection = ".."`
+    pub projection: Option<ProjectionType>,
+    /// `non_key_attributes = [..]`
+    pub non_key_attributes: Vec<LitStr>,
+    /// `provisioned_throughput(..)`, overriding the one of the table for a GSI
+    pub provisioned_throughput: Option<ProvisionedThroughput>,
+    /// `on_demand_throughput(..)`, overriding the one of the table for a GSI
+    pub on_demand_throughput: Option<OnDemandThroughput>,
+}
+
+/// `provisioned_throughput(read_capacity_units = .., write_capacity_units = ..)`
+#[derive(Copy, Clone, Debug)]
+pub struct ProvisionedThroughput {
+    pub read_capacity_units: i64,
+    pub write_capacity_units: i64,
+    pub span: Span,
+}
+
+/// `on_demand_throughput(max_read_request_units = .., max_write_request_units = ..)`
+#[derive(Copy, Clone, Debug)]
+pub struct OnDemandThroughput {
+    pub max_read_request_units: Option<i64>,
+    pub max_write_request_units: Option<i64>,
+    pub span: Span,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BillingMode {
+    /// `"provisioned"`
+    Provisioned,
+    /// `"pay_per_request"`
+    PayPerRequest,
+}
+
+impl std::fmt::Display for BillingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            Self::Provisioned => "provisioned",
+            Self::PayPerRequest => "pay_per_request",
+        };
+        write!(f, "{val}")
+    }
+}
+
+impl BillingMode {
+    const VARIANTS: &'static [&'static str] = &["provisioned", "pay_per_request"];
+
+    fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "provisioned" => Ok(Self::Provisioned),
+            "pay_per_request" => Ok(Self::PayPerRequest),
+            value => Err(unknown_value_error(
+                lit,
+                KEY_BILLING_MODE,
+                value,
+                Self::VARIANTS,
+            )),
+        }
+    }
+}
+
+/// what the stream of the table records about a modified item
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StreamViewType {
+    /// `"new_and_old_images"`
+    NewAndOldImages,
+    /// `"new_image"`
+    NewImage,
+    /// `"old_image"`
+    OldImage,
+    /// `"keys_only"`
+    KeysOnly,
+}
+
+impl StreamViewType {
+    const VARIANTS: &'static [&'static str] =
+        &["new_and_old_images", "new_image", "old_image", "keys_only"];
+
+    fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "new_and_old_images" => Ok(Self::NewAndOldImages),
+            "new_image" => Ok(Self::NewImage),
+            "old_image" => Ok(Self::OldImage),
+            "keys_only" => Ok(Self::KeysOnly),
+            value => Err(unknown_value_error(lit, KEY_STREAM, value, Self::VARIANTS)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TableClass {
+    /// `"standard"`
+    Standard,
+    /// `"standard_infrequent_access"`
+    StandardInfrequentAccess,
+}
+
+impl TableClass {
+    const VARIANTS: &'static [&'static str] = &["standard", "standard_infrequent_access"];
+
+    fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "standard" => Ok(Self::Standard),
+            "standard_infrequent_access" => Ok(Self::StandardInfrequentAccess),
+            value => Err(unknown_value_error(
+                lit,
+                KEY_TABLE_CLASS,
+                value,
+                Self::VARIANTS,
+            )),
+        }
+    }
+}
+
+/// attributes copied from the table into a secondary index
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ProjectionType {
+    /// `"all"`
+    All,
+    /// `"keys_only"`
+    KeysOnly,
+    /// `"include"`: the keys and the non-key attributes
+    Include,
+}
+
+impl std::fmt::Display for ProjectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            Self::All => "all",
+            Self::KeysOnly => "keys_only",
+            Self::Include => "include",
+        };
+        write!(f, "{val}")
+    }
+}
+
+impl ProjectionType {
+    const VARIANTS: &'static [&'static str] = &["all", "keys_only", "include"];
+
+    fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "all" => Ok(Self::All),
+            "keys_only" => Ok(Self::KeysOnly),
+            "include" => Ok(Self::Include),
+            value => Err(unknown_value_error(
+                lit,
+                KEY_PROJECTION,
+                value,
+                Self::VARIANTS,
+            )),
+        }
+    }
+}
+
+/// conversion of a field by the functions of a module instead of the conversions of its type,
+/// `#[aws_dynamo(with = "..", attribute_type = "..")]`
+#[derive(Clone, Debug, Default)]
+pub struct FieldConversion {
+    /// module holding `to_attribute_value` and `from_attribute_value` of the field type
+    pub with: Option<Path>,
+    /// type of the attribute the module converts into, which a key field must declare
+    pub attribute_type: Option<AttributeValueType>,
+}
+
+const ATTRIBUTE_TYPE_VARIANTS: &[&str] =
+    &["S", "N", "B", "BOOL", "NULL", "M", "L", "SS", "NS", "BS"];
+
+fn parse_attribute_type(lit: &LitStr) -> Result<AttributeValueType> {
+    match lit.value().as_str() {
+        "S" => Ok(AttributeValueType::S),
+        "N" => Ok(AttributeValueType::N),
+        "B" => Ok(AttributeValueType::B),
+        "BOOL" => Ok(AttributeValueType::Bool),
+        "NULL" => Ok(AttributeValueType::Null),
+        "M" => Ok(AttributeValueType::M),
+        "L" => Ok(AttributeValueType::L),
+        "SS" => Ok(AttributeValueType::Ss),
+        "NS" => Ok(AttributeValueType::Ns),
+        "BS" => Ok(AttributeValueType::Bs),
+        value => Err(unknown_value_error(
+            lit,
+            KEY_ATTRIBUTE_TYPE,
+            value,
+            ATTRIBUTE_TYPE_VARIANTS,
+        )),
+    }
+}
+
+/// parses `with` and `attribute_type` of a field ahead of its other attributes,
+/// as the conversion decides the type of the attribute keys and indexes are declared with.
+/// The other keys are validated by the parser of the derive.
+pub fn parse_field_conversion(attrs: &[Attribute]) -> Result<FieldConversion> {
+    let mut conversion = FieldConversion::default();
+    let mut attribute_type_lit = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident(AWS_DYNAMO_ATTR_META_ENTRY) {
+            continue;
+        }
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            let Meta::NameValue(name_value) = meta else {
+                continue;
+            };
+            let is_with = name_value.path.is_ident(KEY_WITH);
+            if !is_with && !name_value.path.is_ident(KEY_ATTRIBUTE_TYPE) {
+                continue;
+            }
+            let lit = match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => lit.clone(),
+                value => return Err(Error::new_spanned(value, "expected string literal")),
+            };
+            let duplicated = if is_with {
+                conversion.with.replace(lit.parse::<Path>()?).is_some()
+            } else {
+                conversion.attribute_type = Some(parse_attribute_type(&lit)?);
+                attribute_type_lit.replace(lit).is_some()
+            };
+            if duplicated {
+                let path = name_value.path.to_token_stream().to_string();
+                return Err(Error::new_spanned(
+                    &name_value.path,
+                    format!("duplicate {AWS_DYNAMO_ATTR_META_ENTRY} attribute `{path}`"),
+                ));
+            }
+        }
+    }
+
+    if let (None, Some(lit)) = (&conversion.with, attribute_type_lit) {
+        return Err(Error::new_spanned(
+            lit,
+            format!("`{KEY_ATTRIBUTE_TYPE}` requires `{KEY_WITH}`"),
+        ));
+    }
+    Ok(conversion)
+}
+
+/// how empty `Vec`s converted into SS, NS or BS are written, as DynamoDB rejects empty sets
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum EmptySets {
+    /// `"omit"`: the attribute is not written, or written as an empty L inside a list
+    #[default]
+    Omit,
+    /// `"list"`: the attribute is written as an empty L
+    List,
+    /// `"error"`: the attribute is written as is, and `try_to_attribute_value` returns an error
+    Error,
+}
+
+impl EmptySets {
+    const VARIANTS: &'static [&'static str] = &["omit", "list", "error"];
+
+    fn parse(lit: &LitStr) -> Result<Self> {
+        match lit.value().as_str() {
+            "omit" => Ok(Self::Omit),
+            "list" => Ok(Self::List),
+            "error" => Ok(Self::Error),
+            value => Err(unknown_value_error(
+                lit,
+                &format!("{KEY_EMPTY_SETS} policy"),
+                value,
+                Self::VARIANTS,
+            )),
+        }
+    }
+}
+
+/// error on a value of `key` other than `variants`
+fn unknown_value_error(lit: &LitStr, key: &str, value: &str, variants: &[&str]) -> Error {
+    Error::new_spanned(
+        lit,
+        format!(
+            "unknown {key} `{value}`, expected one of {}",
+            variants
+                .iter()
+                .map(|variant| format!("`{variant}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    )
+}
+
+pub fn parse_struct_attrs(attrs: &[Attribute], known_keys: &[&str]) -> Result<StructAttrs> {
+    let mut struct_attrs = StructAttrs::default();
+
+    for attr in attrs {
+        if attr.path().is_ident(AWS_DYNAMO_ATTR_META_ENTRY) {
+            attr.parse_nested_meta(|meta| {
+                if !known_keys.iter().any(|key| meta.path.is_ident(key)) {
+                    return Err(unknown_attribute_error(&meta, known_keys));
+                }
+
+                if meta.path.is_ident(KEY_TABLE_NAME) {
+                    if struct_attrs.table_name.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.table_name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident(KEY_DENY_UNKNOWN_ATTRIBUTES) {
+                    if struct_attrs.deny_unknown_attributes.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    let allowed = struct_attrs.deny_unknown_attributes.insert(vec![]);
+                    if meta.input.peek(syn::token::Paren) {
+                        let mut has_allow = false;
+                        meta.parse_nested_meta(|nested_meta| {
+                            if !nested_meta.path.is_ident(KEY_ALLOW) {
+                                return Err(unknown_attribute_error(&nested_meta, &[KEY_ALLOW]));
+                            }
+                            if has_allow {
+                                return Err(duplicate_attribute_error(&nested_meta));
+                            }
+                            has_allow = true;
+                            allowed.extend(parse_lit_str_array(&nested_meta)?);
+                            Ok(())
+                        })?;
+                    }
+                } else if meta.path.is_ident(KEY_LENIENT_NUMBERS) {
+                    if struct_attrs.lenient_numbers {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.lenient_numbers = true;
+                } else if meta.path.is_ident(KEY_EMPTY_SETS) {
+                    if struct_attrs.empty_sets.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.empty_sets = Some(EmptySets::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident(KEY_LOCAL_SECONDARY_INDEX) {
+                    let index = parse_index_attrs(&meta, LOCAL_INDEX_KEYS)?;
+                    push_index_attrs(&meta, index, &mut struct_attrs.local_secondary_indexes)?;
+                } else if meta.path.is_ident(KEY_GLOBAL_SECONDARY_INDEX) {
+                    let index = parse_index_attrs(&meta, GLOBAL_INDEX_KEYS)?;
+                    push_index_attrs(&meta, index, &mut struct_attrs.global_secondary_indexes)?;
+                } else if meta.path.is_ident(KEY_BILLING_MODE) {
+                    if struct_attrs.billing_mode.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    struct_attrs.billing_mode = Some((BillingMode::parse(&lit)?, lit.span()));
+                } else if meta.path.is_ident(KEY_PROVISIONED_THROUGHPUT) {
+                    if struct_attrs.provisioned_throughput.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.provisioned_throughput =
+                        Some(parse_provisioned_throughput(&meta)?);
+                } else if meta.path.is_ident(KEY_ON_DEMAND_THROUGHPUT) {
+                    if struct_attrs.on_demand_throughput.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.on_demand_throughput = Some(parse_on_demand_throughput(&meta)?);
+                } else if meta.path.is_ident(KEY_STREAM) {
+                    if struct_attrs.stream.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.stream = Some(StreamViewType::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident(KEY_TABLE_CLASS) {
+                    if struct_attrs.table_class.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.table_class = Some(TableClass::parse(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident(KEY_DELETION_PROTECTION) {
+                    if struct_attrs.deletion_protection {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.deletion_protection = true;
+                } else if meta.path.is_ident(KEY_SSE) {
+                    if struct_attrs.sse.is_some() {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    let sse = struct_attrs.sse.insert(Sse {
+                        kms_master_key_id: None,
+                    });
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|nested_meta| {
+                            if !nested_meta.path.is_ident(KEY_KMS_MASTER_KEY_ID) {
+                                return Err(unknown_attribute_error(
+                                    &nested_meta,
+                                    &[KEY_KMS_MASTER_KEY_ID],
+                                ));
+                            }
+                            if sse.kms_master_key_id.is_some() {
+                                return Err(duplicate_attribute_error(&nested_meta));
+                            }
+                            let lit = nested_meta.value()?.parse::<LitStr>()?;
+                            if lit.value().is_empty() {
+                                return Err(Error::new_spanned(lit, "empty kms_master_key_id"));
+                            }
+                            sse.kms_master_key_id = Some(lit);
+                            Ok(())
+                        })?;
+                    }
+                } else if meta.path.is_ident(KEY_POINT_IN_TIME_RECOVERY) {
+                    if struct_attrs.point_in_time_recovery {
+                        return Err(duplicate_attribute_error(&meta));
+                    }
+                    struct_attrs.point_in_time_recovery = true;
+                }
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(struct_attrs)
+}
+
+/// parses `local_secondary_index(..)` or `global_secondary_index(..)` on top of the struct
+fn parse_index_attrs(meta: &ParseNestedMeta, known_keys: &[&str]) -> Result<IndexAttrs> {
+    let mut index_name = None;
+    let mut projection = None;
+    let mut non_key_attributes = None;
+    let mut provisioned_throughput = None;
+    let mut on_demand_throughput = None;
+
+    meta.parse_nested_meta(|nested_meta| {
+        if !known_keys.iter().any(|key| nested_meta.path.is_ident(key)) {
+            return Err(unknown_attribute_error(&nested_meta, known_keys));
+        }
+
+        if nested_meta.path.is_ident(KEY_INDEX_NAME) {
+            if index_name.is_some() {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            index_name = Some(nested_meta.value()?.parse::<LitStr>()?);
+        } else if nested_meta.path.is_ident(KEY_PROJECTION) {
+            if projection.is_some() {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            projection = Some(ProjectionType::parse(&nested_meta.value()?.parse()?)?);
+        } else if nested_meta.path.is_ident(KEY_PROVISIONED_THROUGHPUT) {
+            if provisioned_throughput.is_some() {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            provisioned_throughput = Some(parse_provisioned_throughput(&nested_meta)?);
+        } else if nested_meta.path.is_ident(KEY_ON_DEMAND_THROUGHPUT) {
+            if on_demand_throughput.is_some() {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            on_demand_throughput = Some(parse_on_demand_throughput(&nested_meta)?);
+        } else {
+            if non_key_attributes.is_some() {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            non_key_attributes = Some(parse_lit_str_array(&nested_meta)?);
+        }
+        Ok(())
+    })?;
+
+    Ok(IndexAttrs {
+        index_name: index_name.ok_or(meta.error(format!("expected `{KEY_INDEX_NAME}`")))?,
+        projection,
+        non_key_attributes: non_key_attributes.unwrap_or_default(),
+        provisioned_throughput,
+        on_demand_throughput,
+    })
+}
+
+/// parses `provisioned_throughput(read_capacity_units = .., write_capacity_units = ..)`,
+/// both of which are required
+fn parse_provisioned_throughput(meta: &ParseNestedMeta) -> Result<ProvisionedThroughput> {
+    let mut read_capacity_units = None;
+    let mut write_capacity_units = None;
+
+    meta.parse_nested_meta(|nested_meta| {
+        let units = if nested_meta.path.is_ident(KEY_READ_CAPACITY_UNITS) {
+            &mut read_capacity_units
+        } else if nested_meta.path.is_ident(KEY_WRITE_CAPACITY_UNITS) {
+            &mut write_capacity_units
+        } else {
+            return Err(unknown_attribute_error(
+                &nested_meta,
+                PROVISIONED_THROUGHPUT_KEYS,
+            ));
+        };
+        if units.is_some() {
+            return Err(duplicate_attribute_error(&nested_meta));
+        }
+        *units = Some(parse_units(&nested_meta)?);
+        Ok(())
+    })?;
+
+    Ok(ProvisionedThroughput {
+        read_capacity_units: read_capacity_units
+            .ok_or(meta.error(format!("expected `{KEY_READ_CAPACITY_UNITS}`")))?,
+        write_capacity_units: write_capacity_units
+            .ok_or(meta.error(format!("expected `{KEY_WRITE_CAPACITY_UNITS}`")))?,
+        span: meta.path.span(),
+    })
+}
+
+/// parses `on_demand_throughput(max_read_request_units = .., max_write_request_units = ..)`
+fn parse_on_demand_throughput(meta: &ParseNestedMeta) -> Result<OnDemandThroughput> {
+    let mut max_read_request_units = None;
+    let mut max_write_request_units = None;
+
+    meta.parse_nested_meta(|nested_meta| {
+        let units = if nested_meta.path.is_ident(KEY_MAX_READ_REQUEST_UNITS) {
+            &mut max_read_request_units
+        } else if nested_meta.path.is_ident(KEY_MAX_WRITE_REQUEST_UNITS) {
+            &mut max_write_request_units
+        } else {
+            return Err(unknown_attribute_error(
+                &nested_meta,
+                ON_DEMAND_THROUGHPUT_KEYS,
+            ));
+        };
+        if units.is_some() {
+            return Err(duplicate_attribute_error(&nested_meta));
+        }
+        *units = Some(parse_units(&nested_meta)?);
+        Ok(())
+    })?;
+
+    if max_read_request_units.is_none() && max_write_request_units.is_none() {
+        return Err(meta.error(format!(
+            "expected `{KEY_MAX_READ_REQUEST_UNITS}` or `{KEY_MAX_WRITE_REQUEST_UNITS}`"
+        )));
+    }
+    Ok(OnDemandThroughput {
+        max_read_request_units,
+        max_write_request_units,
+        span: meta.path.span(),
+    })
+}
+
+/// parses `key = N` of capacity or request units, which are positive
+fn parse_units(meta: &ParseNestedMeta) -> Result<i64> {
+    let lit = meta.value()?.parse::<LitInt>()?;
+    let units = lit.base10_parse::<i64>()?;
+    if units < 1 {
+        return Err(Error::new_spanned(lit, "units must be positive"));
+    }
+    Ok(units)
+}
+
+/// an index is configured at most once on top of the struct
+fn push_index_attrs(
+    meta: &ParseNestedMeta,
+    index: IndexAttrs,
+    indexes: &mut Vec<IndexAttrs>,
+) -> Result<()> {
+    if indexes
+        .iter()
+        .any(|other| other.index_name.value() == index.index_name.value())
+    {
+        return Err(Error::new_spanned(
+            &index.index_name,
+            format!(
+                "duplicate {} `{}`",
+                meta.path.to_token_stream(),
+                index.index_name.value()
+            ),
+        ));
+    }
+    indexes.push(index);
+    Ok(())
+}
+
+/// parses attributes of fields of `Item`, returning whether the field is annotated with `other`
+pub fn parse_item_field_attrs(attrs: &[Attribute]) -> Result<bool> {
+    let mut other = false;
+
+    for attr in attrs {
+        if attr.path().is_ident(AWS_DYNAMO_ATTR_META_ENTRY) {
+            attr.parse_nested_meta(|meta| {
+                if !ITEM_FIELD_KEYS.iter().any(|key| meta.path.is_ident(key)) {
+                    return Err(unknown_attribute_error(&meta, ITEM_FIELD_KEYS));
+                }
+                // parsed by `parse_field_conversion`
+                if meta.path.is_ident(KEY_WITH) {
+                    meta.value()?.parse::<LitStr>()?;
+                    return Ok(());
+                }
+                if other {
+                    return Err(duplicate_attribute_error(&meta));
+                }
+                other = true;
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(other)
+}
+
+/// parses `key = ["a", "b"]`
+pub fn parse_lit_str_array(meta: &ParseNestedMeta) -> Result<Vec<LitStr>> {
+    let content;
+    let value = meta.value()?;
+    bracketed!(content in value);
+    let lits = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+    Ok(lits.into_iter().collect())
+}
+
+pub fn unknown_attribute_error(meta: &ParseNestedMeta, known_keys: &[&str]) -> Error {
+    let path = meta.path.to_token_stream().to_string().replace(' ', "");
+    let msg = match did_you_mean(&path, known_keys) {
+        Some(suggestion) => format!(
+            "unknown {AWS_DYNAMO_ATTR_META_ENTRY} attribute `{path}`, did you mean `{suggestion}`?"
+        ),
+        None => format!(
+            "unknown {AWS_DYNAMO_ATTR_META_ENTRY} attribute `{path}`, expected one of {}",
+            known_keys
+                .iter()
+                .map(|key| format!("`{key}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    Error::new_spanned(&meta.path, msg)
+}
+
+pub fn duplicate_attribute_error(meta: &ParseNestedMeta) -> Error {
+    let path = meta.path.to_token_stream().to_string().replace(' ', "");
+    Error::new_spanned(
+        &meta.path,
+        format!("duplicate {AWS_DYNAMO_ATTR_META_ENTRY} attribute `{path}`"),
+    )
+}
+
+#[cfg(test)]
+mod test_attrs {
+    use crate::attrs::{
+        parse_field_conversion, parse_item_field_attrs, parse_struct_attrs, ProjectionType,
+        StreamViewType, TableClass, ITEM_STRUCT_KEYS, TABLE_STRUCT_KEYS,
+    };
+    use crate::dynamo::attribute_value::AttributeValueType;
+
+    use quote::ToTokens;
+    use syn::{parse_quote, Attribute};
+
+    #[test]
+    fn unknown_struct_attribute() {
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(tabel_name = "Foo")] }];
+        assert_eq!(
+            parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "unknown aws_dynamo attribute `tabel_name`, did you mean `table_name`?"
+        );
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(table_name = "Foo")] }];
+        assert_eq!(
+            parse_struct_attrs(&attrs, ITEM_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "unknown aws_dynamo attribute `table_name`, expected one of `deny_unknown_attributes`, `lenient_numbers`, `empty_sets`"
+        );
+    }
+
+    #[test]
+    fn duplicate_struct_attribute() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[aws_dynamo(table_name = "Foo")] },
+            parse_quote! { #[aws_dynamo(table_name = "Bar")] },
+        ];
+        assert_eq!(
+            parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "duplicate aws_dynamo attribute `table_name`"
+        );
+    }
+
+    #[test]
+    fn index_struct_attribute() {
+        let attrs: Vec<Attribute> = vec![parse_quote! {
+            #[aws_dynamo(global_secondary_index(
+                index_name = "gsi1",
+                projection = "include",
+                non_key_attributes = ["A", "B"]
+            ))]
+        }];
+        let struct_attrs = parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS).unwrap();
+        let index = &struct_attrs.global_secondary_indexes[0];
+        assert_eq!(index.index_name.value(), "gsi1");
+        assert_eq!(index.projection, Some(ProjectionType::Include));
+        assert_eq!(
+            index
+                .non_key_attributes
+                .iter()
+                .map(|lit| lit.value())
+                .collect::<Vec<_>>(),
+            vec!["A", "B"]
+        );
+
+        let attrs: Vec<Attribute> = vec![parse_quote! {
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", projection = "key_only"))]
+        }];
+        assert_eq!(
+            parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "unknown projection `key_only`, expected one of `all`, `keys_only`, `include`"
+        );
+
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[aws_dynamo(local_secondary_index(index_name = "lsi1"))] },
+            parse_quote! { #[aws_dynamo(local_secondary_index(index_name = "lsi1"))] },
+        ];
+        assert_eq!(
+            parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "duplicate local_secondary_index `lsi1`"
+        );
+    }
+
+    #[test]
+    fn stream_struct_attribute() {
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(stream = "new_image")] }];
+        let struct_attrs = parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS).unwrap();
+        assert_eq!(struct_attrs.stream, Some(StreamViewType::NewImage));
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(stream = "new_images")] }];
+        assert_eq!(
+            parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "unknown stream `new_images`, expected one of `new_and_old_images`, `new_image`, `old_image`, `keys_only`"
+        );
+    }
+
+    #[test]
+    fn table_settings_struct_attribute() {
+        let attrs: Vec<Attribute> = vec![parse_quote! {
+            #[aws_dynamo(
+                table_class = "standard_infrequent_access",
+                deletion_protection,
+                sse(kms_master_key_id = "alias/foo"),
+                point_in_time_recovery
+            )]
+        }];
+        let struct_attrs = parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS).unwrap();
+        assert_eq!(
+            struct_attrs.table_class,
+            Some(TableClass::StandardInfrequentAccess)
+        );
+        assert!(struct_attrs.deletion_protection);
+        assert_eq!(
+            struct_attrs
+                .sse
+                .and_then(|sse| sse.kms_master_key_id)
+                .map(|key_id| key_id.value()),
+            Some("alias/foo".to_string())
+        );
+        assert!(struct_attrs.point_in_time_recovery);
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(sse(kms_key_id = "foo"))] }];
+        assert_eq!(
+            parse_struct_attrs(&attrs, TABLE_STRUCT_KEYS)
+                .unwrap_err()
+                .to_string(),
+            "unknown aws_dynamo attribute `kms_key_id`, expected one of `kms_master_key_id`"
+        );
+    }
+
+    #[test]
+    fn field_conversion_attribute() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote! { #[aws_dynamo(hash_key)] },
+            parse_quote! { #[aws_dynamo(with = "crate::user_id", attribute_type = "S")] },
+        ];
+        let conversion = parse_field_conversion(&attrs).unwrap();
+        assert_eq!(
+            conversion.with.to_token_stream().to_string(),
+            "crate :: user_id"
+        );
+        assert_eq!(conversion.attribute_type, Some(AttributeValueType::S));
+
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(attribute_type = "S")] }];
+        assert_eq!(
+            parse_field_conversion(&attrs).unwrap_err().to_string(),
+            "`attribute_type` requires `with`"
+        );
+    }
+
+    #[test]
+    fn unknown_item_field_attribute() {
+        let attrs: Vec<Attribute> = vec![parse_quote! { #[aws_dynamo(othr)] }];
+        assert_eq!(
+            parse_item_field_attrs(&attrs).unwrap_err().to_string(),
+            "unknown aws_dynamo attribute `othr`, did you mean `other`?"
+        );
+    }
+}
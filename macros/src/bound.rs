@@ -0,0 +1,178 @@
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::collections::BTreeSet;
+use syn::{parse_quote, GenericParam, Generics, Lifetime, Type, WherePredicate};
+
+/// lifetime of the attribute map decoded fields borrow from
+pub fn decode_lifetime() -> Lifetime {
+    Lifetime::new("'__de", Span::call_site())
+}
+
+/// adds `bounds` of every type parameter to the where clause.
+/// Type parameters are converted like any field type, so they are bounded by the conversions of a value,
+/// which nested items implement as well.
+fn with_bounds(generics: &Generics, bounds: impl Fn(&Ident) -> Vec<WherePredicate>) -> Generics {
+    let mut generics = generics.clone();
+    let predicates = generics
+        .type_params()
+        .flat_map(|param| bounds(&param.ident))
+        .collect::<Vec<_>>();
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// generics of impls converting the struct into attributes
+pub fn with_encode_bounds(generics: &Generics) -> Generics {
+    with_bounds(generics, |param| {
+        vec![parse_quote! { #param: ::aws_dynamo_derive::__private::ToAttributeValue }]
+    })
+}
+
+/// generics of impls converting borrowed attributes into the struct.
+/// Adds the lifetime of the attribute map, which outlives every lifetime of the struct.
+pub fn with_decode_bounds(generics: &Generics) -> Generics {
+    let de = decode_lifetime();
+    let mut generics = with_bounds(generics, |param| {
+        vec![parse_quote! { #param: ::aws_dynamo_derive::__private::FromAttributeValue<#de> }]
+    });
+    let outlives = generics
+        .lifetimes()
+        .map(|param| -> WherePredicate {
+            let lifetime = &param.lifetime;
+            parse_quote! { #de: #lifetime }
+        })
+        .collect::<Vec<_>>();
+    generics.make_where_clause().predicates.extend(outlives);
+    generics.params.insert(0, parse_quote! { #de });
+    generics
+}
+
+/// generics of impls converting owned attributes into the struct
+pub fn with_owned_decode_bounds(generics: &Generics) -> Generics {
+    with_bounds(generics, |param| {
+        vec![parse_quote! { #param: ::aws_dynamo_derive::__private::FromOwnedAttributeValue }]
+    })
+}
+
+/// keeps the parameters of `generics` named in `types`, and the where predicates on them only
+pub fn with_used_params(generics: &Generics, types: &[&Type]) -> Generics {
+    let mut used = BTreeSet::new();
+    types
+        .iter()
+        .for_each(|ty| collect_names(ty.to_token_stream(), &mut used));
+    let param_name = |param: &GenericParam| match param {
+        GenericParam::Lifetime(param) => param.lifetime.to_string(),
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    };
+    let unused = generics
+        .params
+        .iter()
+        .map(param_name)
+        .filter(|name| !used.contains(name))
+        .collect::<BTreeSet<_>>();
+
+    let mut generics = generics.clone();
+    generics.params = generics
+        .params
+        .into_iter()
+        .filter(|param| !unused.contains(&param_name(param)))
+        .collect();
+    if let Some(where_clause) = &mut generics.where_clause {
+        where_clause.predicates = where_clause
+            .predicates
+            .clone()
+            .into_iter()
+            .filter(|predicate| {
+                let mut names = BTreeSet::new();
+                collect_names(predicate.to_token_stream(), &mut names);
+                names.is_disjoint(&unused)
+            })
+            .collect();
+    }
+    generics
+}
+
+/// collects identifiers and lifetimes, such as `T` and `'a`
+fn collect_names(tokens: TokenStream, names: &mut BTreeSet<String>) {
+    let mut lifetime = false;
+    for token in tokens {
+        match &token {
+            TokenTree::Group(group) => collect_names(group.stream(), names),
+            TokenTree::Ident(ident) if lifetime => {
+                names.insert(format!("'{ident}"));
+            }
+            TokenTree::Ident(ident) => {
+                names.insert(ident.to_string());
+            }
+            TokenTree::Punct(_) | TokenTree::Literal(_) => {}
+        }
+        lifetime = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+    }
+}
+
+#[cfg(test)]
+mod test_bound {
+    use crate::bound::{with_decode_bounds, with_encode_bounds, with_used_params};
+
+    use quote::{quote, ToTokens};
+    use syn::{parse_quote, Generics};
+
+    #[test]
+    fn bound_type_params() {
+        let generics: Generics = parse_quote! { <'a, T: Default, const N: usize> };
+        let generics = with_decode_bounds(&generics);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        assert_eq!(
+            impl_generics.to_token_stream().to_string(),
+            quote! { <'__de, 'a, T: Default, const N: usize> }.to_string()
+        );
+        assert_eq!(
+            where_clause.to_token_stream().to_string(),
+            quote! {
+                where T: ::aws_dynamo_derive::__private::FromAttributeValue<'__de>, '__de: 'a
+            }
+            .to_string()
+        );
+
+        let generics: Generics = parse_quote! { <'a, T: Default, const N: usize> };
+        let generics = with_encode_bounds(&generics);
+        let (_, ty_generics, where_clause) = generics.split_for_impl();
+        assert_eq!(
+            ty_generics.to_token_stream().to_string(),
+            quote! { <'a, T, N> }.to_string()
+        );
+        assert_eq!(
+            where_clause.to_token_stream().to_string(),
+            quote! { where T: ::aws_dynamo_derive::__private::ToAttributeValue }.to_string()
+        );
+
+        let mut generics: Generics = parse_quote! { <T> };
+        generics.where_clause = Some(parse_quote! { where T: Send });
+        assert_eq!(
+            with_encode_bounds(&generics)
+                .where_clause
+                .unwrap()
+                .predicates
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn keep_used_params() {
+        let mut generics: Generics = parse_quote! { <'a, 'b, T: Default, U, const N: usize> };
+        generics.where_clause = Some(parse_quote! { where T: Clone, U: Copy });
+        let types = [parse_quote! { &'a str }, parse_quote! { Vec<T> }];
+        let generics = with_used_params(&generics, &types.iter().collect::<Vec<_>>());
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        assert_eq!(
+            impl_generics.to_token_stream().to_string(),
+            quote! { <'a, T: Default> }.to_string()
+        );
+        assert_eq!(
+            where_clause.to_token_stream().to_string(),
+            quote! { where T: Clone }.to_string()
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use crate::attrs::{EmptySets, FieldConversion, StructAttrs};
+use crate::bound::{
+    decode_lifetime, with_decode_bounds, with_encode_bounds, with_owned_decode_bounds,
+};
+use crate::dynamo::attribute_definition::ScalarAttributeType;
+use crate::dynamo::key_schema::KeySchema;
+use crate::tags::{AWS_DYNAMO_ATTR_META_ENTRY, KEY_OTHER};
+use crate::util::to_pascal_case;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Error, GenericArgument, Generics, LitStr, Meta, PathArguments, Token, Type,
+    Visibility,
+};
+
+#[derive(Clone, Debug)]
+pub struct Container<'a> {
+    /// field of struct
+    pub field_ident: &'a Ident,
+    /// type of field
+    pub ty: &'a Type,
+    /// key schemas parsed from attribute
+    pub key_schemas: Vec<KeySchema>,
+    /// ScalarAttributeTypes parsed from attribute
+    pub attribute_definitions: Vec<ScalarAttributeType>,
+    /// lsi index (index_name, KeySchema)
+    pub local_secondary_index_key_schemas: BTreeMap<String, Vec<KeySchema>>,
+    /// gsi index (index_name, KeySchema)
+    pub global_secondary_index_key_schemas: BTreeMap<String, Vec<KeySchema>>,
+    /// lsi index names projecting the field as a non-key attribute
+    pub local_secondary_index_projections: BTreeSet<String>,
+    /// gsi index names projecting the field as a non-key attribute
+    pub global_secondary_index_projections: BTreeSet<String>,
+    /// placeholder for conversions
+    pub to_attribute_target_ident: &'a TokenStream,
+    /// from Rust type to AttributeValueType
+    pub to_attribute_token_stream: TokenStream,
+    /// from Rust type to AttributeValueType, moving the value instead of copying it
+    pub into_attribute_token_stream: TokenStream,
+    /// from AttributeValueType to Rust type
+    pub from_attribute_token_stream: TokenStream,
+    /// from AttributeValueType to Rust type, taking the value out of the map instead of copying it
+    pub from_owned_attribute_token_stream: TokenStream,
+    /// catch-all field receiving attributes not bound to any other field
+    pub other: bool,
+    /// module converting the field instead of the conversions of its type
+    pub conversion: FieldConversion,
+}
+
+impl<'a> Container<'a> {
+    pub fn new(ident: &'a Ident, ty: &'a Type, to_attribute_target_ident: &'a TokenStream) -> Self {
+        Self {
+            field_ident: ident,
+            ty,
+            key_schemas: vec![],
+            attribute_definitions: vec![],
+            local_secondary_index_key_schemas: BTreeMap::new(),
+            global_secondary_index_key_schemas: BTreeMap::new(),
+            local_secondary_index_projections: BTreeSet::new(),
+            global_secondary_index_projections: BTreeSet::new(),
+            to_attribute_target_ident,
+            to_attribute_token_stream: TokenStream::new(),
+            into_attribute_token_stream: TokenStream::new(),
+            from_attribute_token_stream: TokenStream::new(),
+            from_owned_attribute_token_stream: TokenStream::new(),
+            other: false,
+            conversion: FieldConversion::default(),
+        }
+    }
+}
+
+/// checks whether the field is annotated with `#[aws_dynamo(other)]`
+pub fn parse_other_attr(attrs: &[Attribute]) -> syn::Result<bool> {
+    for attr in attrs {
+        if attr.path().is_ident(AWS_DYNAMO_ATTR_META_ENTRY) {
+            let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            if metas
+                .iter()
+                .any(|meta| matches!(meta, Meta::Path(path) if path.is_ident(KEY_OTHER)))
+            {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// the catch-all field receives the attributes as they are, so it must be a `HashMap<String, AttributeValue>`
+pub fn validate_other_type(ty: &Type) -> syn::Result<()> {
+    let is_ident = |arg: Option<&GenericArgument>, name: &str| {
+        matches!(
+            arg,
+            Some(GenericArgument::Type(Type::Path(path)))
+                if path.path.segments.last().is_some_and(|segment| {
+                    segment.ident == name && segment.arguments.is_none()
+                })
+        )
+    };
+    let is_attribute_map = match ty {
+        Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "HashMap"
+                && matches!(
+                    &segment.arguments,
+                    PathArguments::AngleBracketed(args)
+                        if args.args.len() == 2
+                            && is_ident(args.args.first(), "String")
+                            && is_ident(args.args.last(), "AttributeValue")
+                )
+        }),
+        _ => false,
+    };
+    if !is_attribute_map {
+        return Err(Error::new_spanned(
+            ty,
+            "`other` field must be `HashMap<String, AttributeValue>`",
+        ));
+    }
+    Ok(())
+}
+
+/// the catch-all field holds the attributes as they are, so it cannot be converted `with` a module
+pub fn reject_other_conversion(container: &Container) -> syn::Result<()> {
+    match &container.conversion.with {
+        Some(with) => Err(Error::new_spanned(
+            with,
+            "`other` field cannot be converted `with` a module",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// returns the catch-all field, failing if more than one field is annotated with `other`
+pub fn get_other_container<'c, 'a>(
+    containers: &'c [Container<'a>],
+) -> syn::Result<Option<&'c Container<'a>>> {
+    let mut others = containers.iter().filter(|c| c.other);
+    let other = others.next();
+    if let Some(duplicated) = others.next() {
+        return Err(Error::new(
+            duplicated.field_ident.span(),
+            "only one `other` field is allowed",
+        ));
+    }
+    Ok(other)
+}
+
+/// names of attributes bound to fields, excluding the catch-all field
+fn known_attribute_names(containers: &[Container]) -> Vec<String> {
+    containers
+        .iter()
+        .filter(|c| !c.other)
+        .map(|c| to_pascal_case(&c.field_ident.to_string()))
+        .collect()
+}
+
+/// expands a guard recording the unexpected attribute names as an error
+fn expand_deny_unknown_attributes(
+    containers: &[Container],
+    struct_attrs: &StructAttrs,
+    from_attribute_ident: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let Some(allowed) = &struct_attrs.deny_unknown_attributes else {
+        return Ok(TokenStream::new());
+    };
+
+    if let Some(other) = get_other_container(containers)? {
+        return Err(Error::new(
+            other.field_ident.span(),
+            "`other` field cannot be used with `deny_unknown_attributes`",
+        ));
+    }
+
+    let known_attribute_names = known_attribute_names(containers);
+    let allowed = allowed.iter().map(LitStr::value);
+
+    Ok(quote! {
+        let __private_unknown_keys = #from_attribute_ident
+            .keys()
+            .filter(|__private_key| {
+                ![#( #known_attribute_names, )* #( #allowed ),*].contains(&__private_key.as_str())
+            })
+            .map(|__private_key| __private_key.to_string())
+            .collect::<Vec<_>>();
+        if !__private_unknown_keys.is_empty() {
+            ::aws_dynamo_derive::__private::record(
+                &mut __private_error,
+                ::aws_dynamo_derive::DynamoConversionError::unknown_attributes(
+                    __private_unknown_keys,
+                )
+                .into(),
+            )?;
+        }
+    })
+}
+
+/// expands the body of `FromAttributeMap::from_attribute_map`, generic over the error type `__E`.
+/// Each field is decoded separately so that every failure can be recorded when `__E` accumulates.
+/// If `owned`, the body of `from_owned_attribute_map` is expanded instead, which removes the
+/// attributes from the map and leaves the unknown ones to the `other` field.
+fn expand_from_attribute_map_body(
+    containers: &[Container],
+    struct_attrs: &StructAttrs,
+    from_attribute_ident: &TokenStream,
+    owned: bool,
+) -> syn::Result<TokenStream> {
+    let deny_unknown_attributes =
+        expand_deny_unknown_attributes(containers, struct_attrs, from_attribute_ident)?;
+    let known_attribute_names = known_attribute_names(containers);
+
+    let mut decode_fields = vec![];
+    let mut init_fields = vec![];
+    for (i, c) in containers.iter().enumerate() {
+        let field_ident = c.field_ident;
+        if c.other && owned {
+            init_fields.push(quote! {
+                #field_ident: #from_attribute_ident
+            });
+        } else if c.other {
+            init_fields.push(quote! {
+                #field_ident: #from_attribute_ident
+                    .iter()
+                    .filter(|(__private_key, _)| {
+                        ![#( #known_attribute_names ),*].contains(&__private_key.as_str())
+                    })
+                    .map(|(__private_key, __private_value)| {
+                        (__private_key.to_string(), __private_value.clone())
+                    })
+                    .collect()
+            });
+        } else {
+            let decoded = format_ident!("__private_field_{i}");
+            let ident_key = to_pascal_case(&field_ident.to_string());
+            let from_attribute_token = if owned {
+                &c.from_owned_attribute_token_stream
+            } else {
+                &c.from_attribute_token_stream
+            };
+            decode_fields.push(quote! {
+                let #decoded = ::aws_dynamo_derive::__private::record_field(
+                    &mut __private_error,
+                    ::aws_dynamo_derive::__private::with_key::<_, __E>(#ident_key, || {
+                        Ok(#from_attribute_token)
+                    }),
+                )?;
+            });
+            init_fields.push(quote! {
+                #field_ident: #decoded.unwrap()
+            });
+        }
+    }
+
+    let take_ownership = owned.then(|| {
+        quote! {
+            #[allow(unused_mut)]
+            let mut #from_attribute_ident = #from_attribute_ident;
+        }
+    });
+
+    let options = expand_options(struct_attrs);
+
+    Ok(quote! {
+        #take_ownership
+        #options
+        #[allow(unused_mut)]
+        let mut __private_error: Option<__E> = None;
+        #deny_unknown_attributes
+        #( #decode_fields )*
+        ::aws_dynamo_derive::__private::finish(__private_error)?;
+        Ok(Self { #( #init_fields ),* })
+    })
+}
+
+/// inserts a field converted by `to_attribute` into `map`, following the empty set policy
+fn expand_map_insert(container: &Container, to_attribute: &TokenStream) -> TokenStream {
+    let ident_key = to_pascal_case(&container.field_ident.to_string());
+    quote! {
+        __private_options.insert(&mut map, #ident_key.to_string(), #to_attribute);
+    }
+}
+
+/// expands the conversion options of the struct, which every field type converts by
+pub fn expand_options(struct_attrs: &StructAttrs) -> TokenStream {
+    let lenient_numbers = struct_attrs.lenient_numbers;
+    let empty_sets = match struct_attrs.empty_sets.unwrap_or_default() {
+        EmptySets::Omit => quote! { Omit },
+        EmptySets::List => quote! { List },
+        EmptySets::Error => quote! { Error },
+    };
+    quote! {
+        let __private_options = ::aws_dynamo_derive::__private::Options {
+            lenient_numbers: #lenient_numbers,
+            empty_sets: ::aws_dynamo_derive::__private::EmptySets::#empty_sets,
+        };
+    }
+}
+
+pub fn expand_impl_conversions(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    containers: &[Container],
+    struct_attrs: &StructAttrs,
+) -> syn::Result<Vec<TokenStream>> {
+    let mut impls = vec![];
+
+    let encode_generics = with_encode_bounds(generics);
+    let (encode_impl_generics, ty_generics, encode_where_clause) = encode_generics.split_for_impl();
+    let decode_generics = with_decode_bounds(generics);
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+    let owned_decode_generics = with_owned_decode_bounds(generics);
+    let (owned_decode_impl_generics, _, owned_decode_where_clause) =
+        owned_decode_generics.split_for_impl();
+    let de = decode_lifetime();
+    // fields of structs with lifetimes may borrow from the attributes, so they cannot be taken out
+    let decode_owned = generics.lifetimes().next().is_none();
+
+    let other_container = get_other_container(containers)?;
+    let other_len = other_container.map(|c| {
+        let field_ident = c.field_ident;
+        quote! { + value.#field_ident.len() }
+    });
+    let other_extend = other_container.map(|c| {
+        let field_ident = c.field_ident;
+        quote! {
+            map.extend(value.#field_ident.iter().map(|(__private_key, __private_value)| {
+                (__private_key.to_string(), __private_value.clone())
+            }));
+        }
+    });
+    let other_extend_owned = other_container.map(|c| {
+        let field_ident = c.field_ident;
+        quote! { map.extend(value.#field_ident); }
+    });
+
+    let options = expand_options(struct_attrs);
+    let field_containers = containers.iter().filter(|c| !c.other).collect::<Vec<_>>();
+    let field_count = field_containers.len();
+    let map_inserts = field_containers
+        .iter()
+        .map(|c| expand_map_insert(c, &c.to_attribute_token_stream))
+        .collect::<Vec<_>>();
+    let map_inserts_owned = field_containers
+        .iter()
+        .map(|c| expand_map_insert(c, &c.into_attribute_token_stream))
+        .collect::<Vec<_>>();
+
+    // attributes of the table and index keys, which must not be empty
+    let key_names = containers
+        .iter()
+        .filter(|c| {
+            !c.key_schemas.is_empty()
+                || !c.local_secondary_index_key_schemas.is_empty()
+                || !c.global_secondary_index_key_schemas.is_empty()
+        })
+        .map(|c| to_pascal_case(&c.field_ident.to_string()))
+        .collect::<Vec<_>>();
+
+    let from_attribute_map_body =
+        expand_from_attribute_map_body(containers, struct_attrs, &quote! { value }, false)?;
+    let from_owned_attribute_map_body =
+        expand_from_attribute_map_body(containers, struct_attrs, &quote! { value }, true)?;
+
+    impls.push(quote! {
+        impl #encode_impl_generics From<#ident #ty_generics> for ::std::collections::HashMap<
+            ::std::string::String,
+            ::aws_sdk_dynamodb::types::AttributeValue>
+        #encode_where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                ::aws_dynamo_derive::__private::ToAttributeMap::into_attribute_map(value)
+            }
+        }
+    });
+
+    impls.push(quote! {
+        impl #encode_impl_generics From<&#ident #ty_generics> for ::std::collections::HashMap<
+            ::std::string::String,
+            ::aws_sdk_dynamodb::types::AttributeValue>
+        #encode_where_clause {
+            fn from(value: &#ident #ty_generics) -> Self {
+                ::aws_dynamo_derive::__private::ToAttributeMap::to_attribute_map(value)
+            }
+        }
+    });
+
+    impls.push(quote! {
+        impl #encode_impl_generics ::aws_dynamo_derive::__private::ToAttributeMap
+        for #ident #ty_generics #encode_where_clause {
+            fn to_attribute_map(&self) -> ::std::collections::HashMap<
+                ::std::string::String,
+                ::aws_sdk_dynamodb::types::AttributeValue
+            > {
+                let value = self;
+                #options
+                let mut map = ::std::collections::HashMap::with_capacity(#field_count #other_len);
+                #other_extend
+                #( #map_inserts )*
+                map
+            }
+
+            fn into_attribute_map(self) -> ::std::collections::HashMap<
+                ::std::string::String,
+                ::aws_sdk_dynamodb::types::AttributeValue
+            > {
+                let value = self;
+                #options
+                let mut map = ::std::collections::HashMap::with_capacity(#field_count #other_len);
+                #other_extend_owned
+                #( #map_inserts_owned )*
+                map
+            }
+        }
+    });
+
+    if decode_owned {
+        impls.push(quote! {
+            impl #owned_decode_impl_generics TryFrom<::std::collections::HashMap<
+                ::std::string::String,
+                ::aws_sdk_dynamodb::types::AttributeValue>>
+            for #ident #ty_generics #owned_decode_where_clause {
+                type Error = ::aws_dynamo_derive::DynamoConversionError;
+                fn try_from(value: ::std::collections::HashMap<
+                    ::std::string::String,
+                    ::aws_sdk_dynamodb::types::AttributeValue>
+                ) -> Result<Self, Self::Error> {
+                    ::aws_dynamo_derive::__private::FromOwnedAttributeMap::from_owned_attribute_map(value)
+                }
+            }
+        });
+
+        impls.push(quote! {
+            impl #owned_decode_impl_generics ::aws_dynamo_derive::__private::FromOwnedAttributeMap
+            for #ident #ty_generics #owned_decode_where_clause {
+                fn from_owned_attribute_map<__E: ::aws_dynamo_derive::__private::DecodeError>(
+                    value: ::std::collections::HashMap<
+                        ::std::string::String,
+                        ::aws_sdk_dynamodb::types::AttributeValue>
+                ) -> Result<Self, __E> {
+                    #from_owned_attribute_map_body
+                }
+            }
+        });
+    }
+
+    impls.push(quote! {
+        impl #decode_impl_generics TryFrom<&#de ::std::collections::HashMap<
+            ::std::string::String,
+            ::aws_sdk_dynamodb::types::AttributeValue>>
+        for #ident #ty_generics #decode_where_clause {
+            type Error = ::aws_dynamo_derive::DynamoConversionError;
+            fn try_from(value: &#de ::std::collections::HashMap<
+                ::std::string::String,
+                ::aws_sdk_dynamodb::types::AttributeValue>
+            ) -> Result<Self, Self::Error> {
+                ::aws_dynamo_derive::__private::FromAttributeMap::from_attribute_map(value)
+            }
+        }
+    });
+
+    impls.push(quote! {
+        impl #decode_impl_generics ::aws_dynamo_derive::__private::FromAttributeMap<#de>
+        for #ident #ty_generics #decode_where_clause {
+            fn from_attribute_map<__E: ::aws_dynamo_derive::__private::DecodeError>(
+                value: &#de ::std::collections::HashMap<
+                    ::std::string::String,
+                    ::aws_sdk_dynamodb::types::AttributeValue>
+            ) -> Result<Self, __E> {
+                #from_attribute_map_body
+            }
+        }
+    });
+
+    impls.push(quote! {
+        impl #encode_impl_generics #ident #ty_generics #encode_where_clause {
+            /// Converts attributes like `TryFrom`, but collects every failure of fields,
+            /// nested items and list elements instead of stopping at the first one.
+            #vis fn try_from_attribute_value_all_errors<#de>(
+                value: &#de ::std::collections::HashMap<
+                    ::std::string::String,
+                    ::aws_sdk_dynamodb::types::AttributeValue>
+            ) -> Result<Self, ::aws_dynamo_derive::DynamoConversionErrors>
+            where
+                Self: ::aws_dynamo_derive::__private::FromAttributeMap<#de>,
+            {
+                ::aws_dynamo_derive::__private::FromAttributeMap::from_attribute_map(value)
+            }
+
+            /// Converts into attributes like `From<&Self>`, but moves strings and collections
+            /// into the attributes instead of copying them.
+            #vis fn into_item(self) -> ::std::collections::HashMap<
+                ::std::string::String,
+                ::aws_sdk_dynamodb::types::AttributeValue
+            > {
+                ::aws_dynamo_derive::__private::ToAttributeMap::into_attribute_map(self)
+            }
+
+            /// Converts into attributes like `From`, but fails on values DynamoDB would reject:
+            /// numbers exceeding its precision or magnitude, `NaN` and infinite floats, empty sets and empty keys.
+            #vis fn try_to_attribute_value(&self) -> Result<
+                ::std::collections::HashMap<
+                    ::std::string::String,
+                    ::aws_sdk_dynamodb::types::AttributeValue>,
+                ::aws_dynamo_derive::DynamoConversionError
+            > {
+                let value = ::aws_dynamo_derive::__private::ToAttributeMap::to_attribute_map(self);
+                ::aws_dynamo_derive::__private::validate_attributes(&value)?;
+                ::aws_dynamo_derive::__private::validate_keys(&value, &[ #( #key_names ),* ])?;
+                Ok(value)
+            }
+        }
+    });
+
+    Ok(impls)
+}
@@ -1,3 +1,4 @@
 pub mod attribute_definition;
 pub mod attribute_value;
 pub mod key_schema;
+pub mod name;
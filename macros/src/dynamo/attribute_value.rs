@@ -0,0 +1,404 @@
+use crate::container::Container;
+use crate::util::to_pascal_case;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use syn::spanned::Spanned;
+use syn::{Error, GenericArgument, Path, PathArguments, Result, Type, TypePath, TypeReference};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AttributeValueType {
+    B,
+    Bs,
+    Bool,
+    L,
+    M,
+    N,
+    Ns,
+    Null,
+    S,
+    Ss,
+}
+
+impl ToTokens for AttributeValueType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append(format_ident!("{}", format!("{:?}", self)));
+    }
+}
+
+/// expands the conversions of a field.
+/// Each type converts through its `ToAttributeValue` and `FromAttributeValue` impls,
+/// so that the conversion is compiled once per type instead of inlined into every field.
+pub fn expand_attribute_value<'a>(
+    from_attribute_ident: &TokenStream,
+    mut container: Container<'a>,
+) -> Result<(Container<'a>, AttributeValueType)> {
+    if let Some(with) = container.conversion.with.clone() {
+        let attribute_value_type = container
+            .conversion
+            .attribute_type
+            .unwrap_or(AttributeValueType::M);
+        expand_attribute_value_with(from_attribute_ident, &mut container, &with);
+        return Ok((container, attribute_value_type));
+    }
+
+    let attribute_value_type = get_attribute_value_type(container.ty)?;
+
+    let field_ident = container.field_ident;
+    let target = container.to_attribute_target_ident;
+    let field_id_as_key = Literal::string(&to_pascal_case(&field_ident.to_string()));
+
+    container.to_attribute_token_stream = quote! {
+        ::aws_dynamo_derive::__private::ToAttributeValue::to_attribute_value(
+            &#target.#field_ident, __private_options
+        )
+    };
+    container.into_attribute_token_stream = quote! {
+        ::aws_dynamo_derive::__private::ToAttributeValue::into_attribute_value(
+            #target.#field_ident, __private_options
+        )
+    };
+    container.from_attribute_token_stream = quote! {
+        ::aws_dynamo_derive::__private::decode_field::<_, __E>(
+            #from_attribute_ident.get(#field_id_as_key), __private_options
+        )?
+    };
+    container.from_owned_attribute_token_stream = quote! {
+        ::aws_dynamo_derive::__private::decode_owned_field::<_, __E>(
+            #from_attribute_ident.remove(#field_id_as_key), __private_options
+        )?
+    };
+
+    Ok((container, attribute_value_type))
+}
+
+/// expands the conversions of a field by the functions of its `with` module,
+/// which convert the field type from and into any attribute, or fail with a `DynamoConversionError`
+fn expand_attribute_value_with(
+    from_attribute_ident: &TokenStream,
+    container: &mut Container,
+    with: &Path,
+) {
+    let field_ident = container.field_ident;
+    let target = container.to_attribute_target_ident;
+    let field_id_as_key = Literal::string(&to_pascal_case(&field_ident.to_string()));
+
+    container.to_attribute_token_stream = quote! {
+        #with::to_attribute_value(&#target.#field_ident)
+    };
+    container.into_attribute_token_stream = container.to_attribute_token_stream.clone();
+    container.from_attribute_token_stream = quote! {
+        ::aws_dynamo_derive::__private::decode_field_with::<_, __E>(
+            #from_attribute_ident.get(#field_id_as_key), #with::from_attribute_value
+        )?
+    };
+    container.from_owned_attribute_token_stream = quote! {
+        ::aws_dynamo_derive::__private::decode_field_with::<_, __E>(
+            #from_attribute_ident.remove(#field_id_as_key).as_ref(), #with::from_attribute_value
+        )?
+    };
+}
+
+/// validates a field type and returns the type of attribute it converts into
+pub fn get_attribute_value_type(ty: &Type) -> Result<AttributeValueType> {
+    match ty {
+        Type::Path(path) => get_path_type(path),
+        Type::Reference(reference) => get_reference_type(reference),
+        _ => Err(Error::new(ty.span(), "unsupported type")),
+    }
+}
+
+fn get_path_type(path: &TypePath) -> Result<AttributeValueType> {
+    let path_segment = path
+        .path
+        .segments
+        .last()
+        .ok_or(Error::new(path.span(), "segment not found"))?;
+
+    Ok(match path_segment.ident.to_string().as_str() {
+        "Vec" => {
+            let abga = match &path_segment.arguments {
+                PathArguments::AngleBracketed(abga) => abga,
+                _ => {
+                    return Err(Error::new(
+                        path_segment.arguments.span(),
+                        "Vec should be angle bracketed",
+                    ))
+                }
+            };
+            match abga
+                .args
+                .last()
+                .ok_or(Error::new(abga.span(), "argument no found"))?
+            {
+                GenericArgument::Type(ty) => match get_attribute_value_type(ty)? {
+                    AttributeValueType::B => AttributeValueType::Bs,
+                    AttributeValueType::N => AttributeValueType::Ns,
+                    AttributeValueType::S => AttributeValueType::Ss,
+                    _ => AttributeValueType::L,
+                },
+                _ => return Err(Error::new(path_segment.span(), "type expected")),
+            }
+        }
+        "HashMap" => {
+            let abga = match &path_segment.arguments {
+                PathArguments::AngleBracketed(abga) => abga,
+                _ => {
+                    return Err(Error::new(
+                        path_segment.arguments.span(),
+                        "Vec should be angle bracketed",
+                    ))
+                }
+            };
+
+            let key_ty = abga
+                .args
+                .first()
+                .ok_or(Error::new(abga.args.span(), "key type not found"))?;
+
+            let key_type_validation_msg = "key type of HashMap must be String";
+
+            match key_ty {
+                GenericArgument::Type(Type::Path(tp)) => {
+                    if tp
+                        .path
+                        .segments
+                        .last()
+                        .ok_or(Error::new(tp.span(), "argument not found"))?
+                        .ident
+                        != "String"
+                    {
+                        return Err(Error::new(
+                            tp.path.segments.last().span(),
+                            key_type_validation_msg,
+                        ));
+                    }
+                }
+                _ => return Err(Error::new(key_ty.span(), key_type_validation_msg)),
+            }
+
+            let value_ty = abga
+                .args
+                .last()
+                .ok_or(Error::new(abga.args.span(), "value type not found"))?;
+
+            match value_ty {
+                GenericArgument::Type(ty) => {
+                    get_attribute_value_type(ty)?;
+                    AttributeValueType::M
+                }
+                _ => return Err(Error::new(key_ty.span(), "value type not found")),
+            }
+        }
+        "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "i128" | "u128" | "f32"
+        | "f64" => AttributeValueType::N,
+        "String" => AttributeValueType::S,
+        "Blob" => AttributeValueType::B,
+        "bool" => AttributeValueType::Bool,
+        "Option" => AttributeValueType::Null,
+        // nested items
+        _ => AttributeValueType::M,
+    })
+}
+
+/// borrowed `&str` and `&[u8]`, decoded without copying out of the attributes
+fn get_reference_type(reference: &TypeReference) -> Result<AttributeValueType> {
+    let unsupported = || {
+        Error::new(
+            reference.span(),
+            "only `&str` and `&[u8]` are supported as borrowed types",
+        )
+    };
+    if reference.mutability.is_some() {
+        return Err(unsupported());
+    }
+
+    match reference.elem.as_ref() {
+        Type::Path(path) if path.path.is_ident("str") => Ok(AttributeValueType::S),
+        Type::Slice(slice) if matches!(slice.elem.as_ref(), Type::Path(path) if path.path.is_ident("u8")) => {
+            Ok(AttributeValueType::B)
+        }
+        _ => Err(unsupported()),
+    }
+}
+
+#[cfg(test)]
+mod test_attribute_value {
+    use crate::dynamo::attribute_value::{
+        expand_attribute_value, get_attribute_value_type, AttributeValueType, Container,
+    };
+
+    use proc_macro2::{Ident, TokenStream};
+    use quote::quote;
+    use syn::{parse_quote, Result, Type};
+    use test_context::{test_context, TestContext};
+
+    struct AttrValueCtx {
+        to_attribute_ident: Ident,
+        to_attribute_target_ident: TokenStream,
+        from_attribute_ident: TokenStream,
+        ty: Type,
+    }
+    impl TestContext for AttrValueCtx {
+        fn setup() -> Self {
+            let to_attribute_ident = parse_quote! { foo };
+            let to_attribute_target_ident = parse_quote! { self };
+            let from_attribute_ident = quote! { __private_from_attribute_value };
+            let ty = parse_quote! { Vec<Vec<String>> };
+
+            Self {
+                to_attribute_ident,
+                to_attribute_target_ident,
+                from_attribute_ident,
+                ty,
+            }
+        }
+    }
+
+    fn assert_types(cases: Vec<(Type, AttributeValueType)>) -> Result<()> {
+        for (ty, expected) in cases {
+            assert_eq!(get_attribute_value_type(&ty)?, expected, "{ty:?}");
+        }
+        Ok(())
+    }
+
+    #[test_context(AttrValueCtx)]
+    #[test]
+    fn test_field_conversions(ctx: &mut AttrValueCtx) -> Result<()> {
+        let container = Container::new(
+            &ctx.to_attribute_ident,
+            &ctx.ty,
+            &ctx.to_attribute_target_ident,
+        );
+        let (container, root_ty) = expand_attribute_value(&ctx.from_attribute_ident, container)?;
+        assert_eq!(root_ty, AttributeValueType::L);
+
+        let expected = [
+            (
+                container.to_attribute_token_stream,
+                quote! {
+                    ::aws_dynamo_derive::__private::ToAttributeValue::to_attribute_value(
+                        &self.foo, __private_options
+                    )
+                },
+            ),
+            (
+                container.into_attribute_token_stream,
+                quote! {
+                    ::aws_dynamo_derive::__private::ToAttributeValue::into_attribute_value(
+                        self.foo, __private_options
+                    )
+                },
+            ),
+            (
+                container.from_attribute_token_stream,
+                quote! {
+                    ::aws_dynamo_derive::__private::decode_field::<_, __E>(
+                        __private_from_attribute_value.get("Foo"), __private_options
+                    )?
+                },
+            ),
+            (
+                container.from_owned_attribute_token_stream,
+                quote! {
+                    ::aws_dynamo_derive::__private::decode_owned_field::<_, __E>(
+                        __private_from_attribute_value.remove("Foo"), __private_options
+                    )?
+                },
+            ),
+        ];
+        for (ts, expected) in expected {
+            assert_eq!(ts.to_string(), expected.to_string());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_simple_types() -> Result<()> {
+        let mut cases = vec![
+            (parse_quote! { String }, AttributeValueType::S),
+            (parse_quote! { Blob }, AttributeValueType::B),
+            (parse_quote! { bool }, AttributeValueType::Bool),
+            (parse_quote! { Option<()> }, AttributeValueType::Null),
+            (parse_quote! { Inner }, AttributeValueType::M),
+        ];
+        for ty in [
+            "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "i128", "u128", "f32", "f64",
+        ] {
+            cases.push((syn::parse_str(ty)?, AttributeValueType::N));
+        }
+        assert_types(cases)
+    }
+
+    #[test]
+    fn test_list_types() -> Result<()> {
+        assert_types(vec![
+            (parse_quote! { Vec<String> }, AttributeValueType::Ss),
+            (parse_quote! { Vec<u8> }, AttributeValueType::Ns),
+            (parse_quote! { Vec<i128> }, AttributeValueType::Ns),
+            (parse_quote! { Vec<Blob> }, AttributeValueType::Bs),
+            (parse_quote! { Vec<bool> }, AttributeValueType::L),
+            (parse_quote! { Vec<Inner> }, AttributeValueType::L),
+            (parse_quote! { Vec<Vec<i32>> }, AttributeValueType::L),
+            (
+                parse_quote! { Vec<HashMap<String, u8>> },
+                AttributeValueType::L,
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_map_not_string_key_fail() {
+        let map = parse_quote! { HashMap<i32, String> };
+        assert_eq!(
+            get_attribute_value_type(&map).unwrap_err().to_string(),
+            "key type of HashMap must be String"
+        );
+
+        let nested = parse_quote! { Vec<HashMap<String, HashMap<u8, u8>>> };
+        assert_eq!(
+            get_attribute_value_type(&nested).unwrap_err().to_string(),
+            "key type of HashMap must be String"
+        );
+    }
+
+    #[test]
+    fn test_map() -> Result<()> {
+        assert_types(vec![
+            (parse_quote! { HashMap<String, u8> }, AttributeValueType::M),
+            (
+                parse_quote! { HashMap<String, Vec<String>> },
+                AttributeValueType::M,
+            ),
+            (
+                parse_quote! { HashMap<String, Vec<HashMap<String, String>>> },
+                AttributeValueType::M,
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_borrowed_types() -> Result<()> {
+        assert_types(vec![
+            (parse_quote! { &'a str }, AttributeValueType::S),
+            (parse_quote! { &'a [u8] }, AttributeValueType::B),
+            (parse_quote! { Vec<&'a str> }, AttributeValueType::Ss),
+            (parse_quote! { Vec<&'a [u8]> }, AttributeValueType::Bs),
+            (
+                parse_quote! { HashMap<String, &'a str> },
+                AttributeValueType::M,
+            ),
+        ])?;
+
+        let unsupported: [Type; 2] = [parse_quote! { &'a mut str }, parse_quote! { &'a String }];
+        for ty in unsupported {
+            assert_eq!(
+                get_attribute_value_type(&ty).unwrap_err().to_string(),
+                "only `&str` and `&[u8]` are supported as borrowed types"
+            );
+        }
+
+        Ok(())
+    }
+}
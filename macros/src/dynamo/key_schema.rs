@@ -0,0 +1,268 @@
+use crate::util::to_pascal_case;
+
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use std::fmt::{Display, Formatter};
+use syn::Error;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum KeySchemaType {
+    HashKey,
+    RangeKey,
+}
+
+impl Display for KeySchemaType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            Self::HashKey => "hash_key",
+            Self::RangeKey => "range_key",
+        };
+        write!(f, "{val}")
+    }
+}
+
+pub fn expand_key_schema(id: &Ident, key_type: KeySchemaType) -> TokenStream {
+    let ident = Literal::string(&to_pascal_case(&id.to_string()));
+
+    let key_type = match key_type {
+        KeySchemaType::HashKey => quote! { ::aws_sdk_dynamodb::types::KeyType::Hash },
+        KeySchemaType::RangeKey => quote! { ::aws_sdk_dynamodb::types::KeyType::Range },
+    };
+
+    quote! {
+        aws_sdk_dynamodb::types::KeySchemaElement::builder()
+            .attribute_name(#ident.to_string())
+            .key_type(#key_type)
+            .build()
+            .unwrap()
+    }
+}
+
+/// key schema declared on a field, with the span of its `#[aws_dynamo(...)]` key
+#[derive(Copy, Clone, Debug)]
+pub struct KeySchema {
+    pub key_type: KeySchemaType,
+    pub span: Span,
+}
+
+pub fn validate_and_sort_key_schemas(
+    key_schemas: &mut [(&Ident, KeySchema)],
+    span: Span,
+    index_name: Option<&str>,
+) -> syn::Result<()> {
+    let for_index = index_name
+        .map(|index_name| format!(" for index `{index_name}`"))
+        .unwrap_or_default();
+
+    let hash_keys = key_schemas
+        .iter()
+        .filter(|(_, ks)| ks.key_type == KeySchemaType::HashKey)
+        .collect::<Vec<_>>();
+    if hash_keys.is_empty() {
+        let span = key_schemas.first().map(|(_, ks)| ks.span).unwrap_or(span);
+        return Err(Error::new(span, format!("HashKey not found{for_index}")));
+    }
+    validate_unique_key(&hash_keys, "only one HashKey is allowed", &for_index)?;
+
+    let range_keys = key_schemas
+        .iter()
+        .filter(|(_, ks)| ks.key_type == KeySchemaType::RangeKey)
+        .collect::<Vec<_>>();
+    validate_unique_key(&range_keys, "at most one RangeKey is allowed", &for_index)?;
+
+    key_schemas.sort_by_key(|(_, k)| k.key_type);
+
+    Ok(())
+}
+
+/// a local secondary index shares the hash key of the table and sorts its items
+/// by a range key other than the table range key.
+/// Both key schemas are expected to be sorted by `validate_and_sort_key_schemas`.
+pub fn validate_local_secondary_index(
+    index_name: &str,
+    key_schemas: &[(&Ident, KeySchema)],
+    table_key_schemas: &[(&Ident, KeySchema)],
+    span: Span,
+) -> syn::Result<()> {
+    let find = |key_schemas: &[(&Ident, KeySchema)], key_type| {
+        key_schemas
+            .iter()
+            .find(|(_, ks)| ks.key_type == key_type)
+            .map(|(ident, ks)| (ident.to_string(), ks.span))
+    };
+
+    let Some((table_range_key, _)) = find(table_key_schemas, KeySchemaType::RangeKey) else {
+        let span = key_schemas.first().map(|(_, ks)| ks.span).unwrap_or(span);
+        return Err(Error::new(
+            span,
+            format!("a RangeKey is required on the table for local secondary index `{index_name}`"),
+        ));
+    };
+
+    if let (Some((table_hash_key, _)), Some((hash_key, hash_key_span))) = (
+        find(table_key_schemas, KeySchemaType::HashKey),
+        find(key_schemas, KeySchemaType::HashKey),
+    ) {
+        if hash_key != table_hash_key {
+            return Err(Error::new(
+                hash_key_span,
+                format!(
+                    "HashKey for local secondary index `{index_name}` must be the table HashKey `{table_hash_key}`"
+                ),
+            ));
+        }
+    }
+
+    match find(key_schemas, KeySchemaType::RangeKey) {
+        None => {
+            let span = key_schemas.first().map(|(_, ks)| ks.span).unwrap_or(span);
+            Err(Error::new(
+                span,
+                format!("RangeKey not found for local secondary index `{index_name}`"),
+            ))
+        }
+        Some((range_key, range_key_span)) if range_key == table_range_key => Err(Error::new(
+            range_key_span,
+            format!(
+                "RangeKey for local secondary index `{index_name}` must differ from the table RangeKey `{table_range_key}`"
+            ),
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+/// reports every key declared after the first one, pointing at the first one as well
+fn validate_unique_key(
+    key_schemas: &[&(&Ident, KeySchema)],
+    msg: &str,
+    for_index: &str,
+) -> syn::Result<()> {
+    let Some(((first_ident, first), duplicates)) = key_schemas.split_first() else {
+        return Ok(());
+    };
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+
+    let mut error = duplicates
+        .iter()
+        .map(|(ident, ks)| {
+            Error::new(
+                ks.span,
+                format!("{msg}{for_index}: `{ident}` conflicts with `{first_ident}`"),
+            )
+        })
+        .reduce(|mut acc, e| {
+            acc.combine(e);
+            acc
+        })
+        .expect("duplicates are not empty");
+    error.combine(Error::new(
+        first.span,
+        format!("`{first_ident}` is declared as {} here", first.key_type),
+    ));
+    Err(error)
+}
+
+#[cfg(test)]
+mod test_key_schema {
+    use crate::dynamo::key_schema::{
+        validate_and_sort_key_schemas, validate_local_secondary_index, KeySchema, KeySchemaType,
+    };
+
+    use proc_macro2::{Ident, Span};
+
+    fn key_schema(key_type: KeySchemaType) -> KeySchema {
+        KeySchema {
+            key_type,
+            span: Span::call_site(),
+        }
+    }
+
+    #[test]
+    fn sort_key_schema() {
+        let mut key_schemas = vec![
+            KeySchemaType::HashKey,
+            KeySchemaType::RangeKey,
+            KeySchemaType::HashKey,
+            KeySchemaType::RangeKey,
+        ];
+
+        key_schemas.sort();
+        assert_eq!(
+            key_schemas,
+            vec![
+                KeySchemaType::HashKey,
+                KeySchemaType::HashKey,
+                KeySchemaType::RangeKey,
+                KeySchemaType::RangeKey,
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_key_schemas() {
+        let hk = Ident::new("hk", Span::call_site());
+        let rk = Ident::new("rk", Span::call_site());
+        let other = Ident::new("other", Span::call_site());
+
+        let mut key_schemas = vec![
+            (&rk, key_schema(KeySchemaType::RangeKey)),
+            (&hk, key_schema(KeySchemaType::HashKey)),
+        ];
+        validate_and_sort_key_schemas(&mut key_schemas, Span::call_site(), None).unwrap();
+        assert_eq!(key_schemas[0].0, &hk);
+
+        let mut key_schemas = vec![(&rk, key_schema(KeySchemaType::RangeKey))];
+        let err = validate_and_sort_key_schemas(&mut key_schemas, Span::call_site(), Some("Gsi"))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "HashKey not found for index `Gsi`");
+
+        let mut key_schemas = vec![
+            (&hk, key_schema(KeySchemaType::HashKey)),
+            (&other, key_schema(KeySchemaType::HashKey)),
+        ];
+        let err =
+            validate_and_sort_key_schemas(&mut key_schemas, Span::call_site(), None).unwrap_err();
+        let messages = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                "only one HashKey is allowed: `other` conflicts with `hk`",
+                "`hk` is declared as hash_key here",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_local_secondary_index_key_schemas() {
+        let hk = Ident::new("hk", Span::call_site());
+        let rk = Ident::new("rk", Span::call_site());
+        let lsi_rk = Ident::new("lsi_rk", Span::call_site());
+        let hash = key_schema(KeySchemaType::HashKey);
+        let range = key_schema(KeySchemaType::RangeKey);
+        let table = [(&hk, hash), (&rk, range)];
+        let validate = |key_schemas: &[(&Ident, KeySchema)], table: &[(&Ident, KeySchema)]| {
+            validate_local_secondary_index("lsi1", key_schemas, table, Span::call_site())
+                .map_err(|e| e.to_string())
+        };
+
+        assert_eq!(validate(&[(&hk, hash), (&lsi_rk, range)], &table), Ok(()));
+        assert_eq!(
+            validate(&[(&lsi_rk, hash), (&rk, range)], &table).unwrap_err(),
+            "HashKey for local secondary index `lsi1` must be the table HashKey `hk`"
+        );
+        assert_eq!(
+            validate(&[(&hk, hash)], &table).unwrap_err(),
+            "RangeKey not found for local secondary index `lsi1`"
+        );
+        assert_eq!(
+            validate(&[(&hk, hash), (&rk, range)], &table).unwrap_err(),
+            "RangeKey for local secondary index `lsi1` must differ from the table RangeKey `rk`"
+        );
+        assert_eq!(
+            validate(&[(&hk, hash), (&lsi_rk, range)], &[(&hk, hash)]).unwrap_err(),
+            "a RangeKey is required on the table for local secondary index `lsi1`"
+        );
+    }
+}
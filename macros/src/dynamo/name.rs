@@ -0,0 +1,87 @@
+use proc_macro2::Span;
+use syn::{Error, Result};
+
+/// length of table and index names, in characters
+const NAME_LEN: std::ops::RangeInclusive<usize> = 3..=255;
+/// longest name of a key attribute of the table or an index, in bytes
+const MAX_KEY_ATTRIBUTE_NAME_LEN: usize = 255;
+
+pub fn validate_table_name(name: &str, span: Span) -> Result<()> {
+    validate_name("table", name, span)
+}
+
+pub fn validate_index_name(name: &str, span: Span) -> Result<()> {
+    validate_name("index", name, span)
+}
+
+/// table and index names are 3 to 255 characters of `a-z`, `A-Z`, `0-9`, `_`, `-` and `.`
+fn validate_name(kind: &str, name: &str, span: Span) -> Result<()> {
+    if let Some(ch) = name
+        .chars()
+        .find(|ch| !(ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.')))
+    {
+        return Err(Error::new(
+            span,
+            format!("{kind} name `{name}` contains `{ch}`, only `a-z`, `A-Z`, `0-9`, `_`, `-` and `.` are allowed"),
+        ));
+    }
+    if !NAME_LEN.contains(&name.len()) {
+        return Err(Error::new(
+            span,
+            format!(
+                "{kind} name `{name}` must be {} to {} characters long",
+                NAME_LEN.start(),
+                NAME_LEN.end()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+pub fn validate_key_attribute_name(name: &str, span: Span) -> Result<()> {
+    if name.len() > MAX_KEY_ATTRIBUTE_NAME_LEN {
+        return Err(Error::new(
+            span,
+            format!("key attribute name `{name}` must be at most {MAX_KEY_ATTRIBUTE_NAME_LEN} bytes long"),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_name {
+    use crate::dynamo::name::{
+        validate_index_name, validate_key_attribute_name, validate_table_name,
+    };
+
+    use proc_macro2::Span;
+
+    #[test]
+    fn validate_names() {
+        let span = Span::call_site();
+        assert!(validate_table_name("Foo-Table_1.v2", span).is_ok());
+        assert!(validate_index_name("gsi", span).is_ok());
+        assert!(validate_index_name(&"i".repeat(255), span).is_ok());
+
+        assert_eq!(
+            validate_table_name("Foo Table", span).unwrap_err().to_string(),
+            "table name `Foo Table` contains ` `, only `a-z`, `A-Z`, `0-9`, `_`, `-` and `.` are allowed"
+        );
+        assert_eq!(
+            validate_index_name("ix", span).unwrap_err().to_string(),
+            "index name `ix` must be 3 to 255 characters long"
+        );
+        assert!(validate_index_name(&"i".repeat(256), span).is_err());
+
+        assert!(validate_key_attribute_name(&"K".repeat(255), span).is_ok());
+        assert_eq!(
+            validate_key_attribute_name(&"K".repeat(256), span)
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "key attribute name `{}` must be at most 255 bytes long",
+                "K".repeat(256)
+            )
+        );
+    }
+}
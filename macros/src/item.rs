@@ -0,0 +1,97 @@
+use crate::attrs::{
+    parse_field_conversion, parse_item_field_attrs, parse_struct_attrs, ITEM_STRUCT_KEYS,
+};
+use crate::container::{
+    expand_impl_conversions, reject_other_conversion, validate_other_type, Container,
+};
+use crate::dynamo::attribute_value::expand_attribute_value;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Data, DataStruct, DeriveInput, Error, Result};
+
+pub fn expand_item(input: &mut DeriveInput) -> Result<TokenStream> {
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input;
+
+    let ds = match &data {
+        Data::Struct(ds) => ds,
+        _ => return Err(Error::new(input.span(), "only struct type available")),
+    };
+
+    let struct_attrs = parse_struct_attrs(attrs, ITEM_STRUCT_KEYS)?;
+    let to_attribute_ident = quote! { value };
+    let from_attribute_ident = quote! { value };
+    let containers =
+        get_attribute_types_containers(ds, &to_attribute_ident, &from_attribute_ident)?;
+    let impl_conversions =
+        expand_impl_conversions(vis, ident, generics, &containers, &struct_attrs)?;
+
+    Ok(quote! {
+
+        #(
+            #[allow(clippy::needless_question_mark)]
+            #impl_conversions
+        )*
+    })
+}
+
+fn get_attribute_types_containers<'a>(
+    ds: &'a DataStruct,
+    to_attribute_ident: &'a TokenStream,
+    from_attribute_ident: &'a TokenStream,
+) -> Result<Vec<Container<'a>>> {
+    let mut containers = vec![];
+
+    for field in &ds.fields {
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or(Error::new(field.ident.span(), "field ident not found"))?;
+        let ty = &field.ty;
+
+        let mut container = Container::new(ident, ty, to_attribute_ident);
+        container.conversion = parse_field_conversion(&field.attrs)?;
+        if parse_item_field_attrs(&field.attrs)? {
+            validate_other_type(ty)?;
+            reject_other_conversion(&container)?;
+            container.other = true;
+            containers.push(container);
+            continue;
+        }
+
+        let (container, _) = expand_attribute_value(from_attribute_ident, container)?;
+        containers.push(container);
+    }
+
+    Ok(containers)
+}
+
+#[cfg(test)]
+mod test_item {
+    use crate::item::expand_item;
+
+    use syn::DeriveInput;
+
+    #[test]
+    fn invalid_other_type() {
+        let input = r#"struct Item {
+            #[aws_dynamo(other)]
+            extra: HashMap<String, String>,
+        }"#;
+        let mut input = syn::parse_str::<DeriveInput>(input).unwrap();
+        let error = expand_item(&mut input).unwrap_err();
+        let start = error.span().start();
+        assert_eq!(
+            error.to_string(),
+            "`other` field must be `HashMap<String, AttributeValue>`"
+        );
+        assert_eq!((start.line, start.column), (3, 19));
+    }
+}
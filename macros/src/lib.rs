@@ -0,0 +1,153 @@
+//! Derive macros of [aws-dynamo-derive](https://docs.rs/aws-dynamo-derive).
+//!
+//! This crate is not intended to be used directly. Depend on `aws-dynamo-derive` instead.
+
+mod attrs;
+mod bound;
+mod container;
+mod dynamo;
+mod item;
+mod table;
+mod tags;
+mod util;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive macro for AwsDynamoDb table
+///
+/// Table name can be set by adding `#[aws_dynamo(table_name = "AwesomeFooTable")]` attribute on top of the struct.
+/// It defaults to the struct name, and either must be 3 to 255 characters of `a-z`, `A-Z`, `0-9`, `_`, `-` and `.`,
+/// so a struct named e.g. `Ab` does not compile without a valid `table_name`.
+/// Annotating `#[aws_dynamo(hash_key)]` or `#[aws_dynamo(range_key)]` can set primary of table.
+/// As the spec of aws dynamo db, only one hash key is available per table, and 0 or 1 additional range key is available.
+/// It wouldn't compile if the key constraint is wrong.
+///
+/// #### Example
+/// ```rust
+/// use aws_dynamo_derive::{Table, Item};
+/// #[derive(Table)]
+/// #[aws_dynamo(table_name = "AwesomeFooTable")]
+/// struct FooTable {
+///     #[aws_dynamo(range_key)]
+///     index: u64,
+///     #[aws_dynamo(hash_key)]
+///     #[aws_dynamo(global_secondary_index(index_name = "foo_index_1", hash_key))]
+///     name: String,
+///     temp: i128,
+///     values: Values,
+/// }
+///
+/// #[derive(Item)]
+/// struct Values {
+///     count: u32,
+///     count2: u64,
+/// }
+/// ```
+///
+/// #### CreateTable Example
+///
+/// Indexes, billing mode and throughput are declared with attributes, and `create_table()` sets them
+/// along with the key schemas and attribute definitions.
+///
+/// ```rust,ignore
+/// #[derive(Table)]
+/// #[aws_dynamo(provisioned_throughput(read_capacity_units = 5, write_capacity_units = 5))]
+/// #[aws_dynamo(local_secondary_index(index_name = "lsi1", projection = "keys_only"))]
+/// #[aws_dynamo(global_secondary_index(
+///     index_name = "gsi1",
+///     provisioned_throughput(read_capacity_units = 1, write_capacity_units = 1)
+/// ))]
+/// struct Foo {
+///     #[aws_dynamo(hash_key)]
+///     #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+///     name: String,
+///     #[aws_dynamo(range_key)]
+///     index: u64,
+///     #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+///     created_at: u64,
+///     #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+///     title: String,
+/// }
+///
+/// async fn create_table() {
+///     // accepts CreateTableFluentBuilder
+///     Foo::create_table(client.create_table()).send().await?;
+/// }
+/// ```
+/// `billing_mode`, one of `"provisioned"` and `"pay_per_request"`, is inferred from `provisioned_throughput(..)` or
+/// `on_demand_throughput(max_read_request_units = .., max_write_request_units = ..)` when omitted,
+/// and GSIs take the throughput of the table unless they declare their own.
+/// `get_local_secondary_indexes` and `get_global_secondary_indexes` return the indexes passed to `create_table()`,
+/// and `get_local_secondary_index_key_schemas` and `get_global_secondary_index_key_schemas` their key schemas by index name.
+#[proc_macro_derive(Table, attributes(aws_dynamo))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    table::expand_table(&mut input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive macro for AwsDynamoDb Item
+///
+/// Derives function to convert rust types to aws dynamo AttributeValue types.
+/// Nesting structs is available which converts fields to `AttributeValue::M` type.
+///
+/// #### Example
+/// ```rust,ignore
+/// #[derive(Item)]
+/// struct Values {
+///     count: u32,
+///     count2: u64,
+/// }
+///
+/// async fn put_item() {
+///     let values = Values {
+///         count: 1,
+///         count2: 2
+///     };
+///
+///     let foo = FooTable {
+///         index: 1,
+///         name: "foo".to_string(),
+///         // nested struct derives `Item` converted into AttributeValue::M
+///         value,
+///     };
+///
+///     let config = aws_config::load_from_env().await;
+///     let client = aws_sdk_dynamodb::Client::new(&config);
+///     foo.put_item(client.put_item()).send().await?;
+/// }
+/// ```
+/// #### GetItem with PrimaryKey
+/// ```rust,ignore
+/// async fn get_item_by_primary_key() {
+///     // macro expands input struct for primary key `FooTablePrimaryKey`
+///     let primary_key = FooTable::get_primary_keys(FooTablePrimaryKey {
+///         index: 1,
+///         name: "foo".to_string()
+///     });
+///
+///     // query with primary keys
+///     // aws_sdk_dynamodb::Client
+///     let resp = client
+///         .get_item()
+///         .table_name(FooTable::get_table_name())
+///         .set_key(Some(primary_key))
+///         .send()
+///         .await?
+///         .item();
+///
+///     let item = resp.item().unwrap();
+///     // returns error if type conversion is invalid
+///     let converted = FooTable::from_attribute_value(item).unwrap();
+/// }
+/// ```
+/// You can find how the macro handles for other types on README
+#[proc_macro_derive(Item, attributes(aws_dynamo))]
+pub fn derive_item(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    item::expand_item(&mut input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
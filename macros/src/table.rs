@@ -0,0 +1,848 @@
+mod index;
+mod key;
+mod parser;
+mod projection;
+mod settings;
+mod stream;
+mod tags;
+mod throughput;
+
+use crate::attrs::{parse_field_conversion, parse_struct_attrs, StructAttrs, TABLE_STRUCT_KEYS};
+use crate::bound::{decode_lifetime, with_encode_bounds};
+use crate::container;
+use crate::container::{parse_other_attr, reject_other_conversion, validate_other_type, Container};
+use crate::dynamo::attribute_definition::ScalarAttributeType;
+use crate::dynamo::attribute_value::{expand_attribute_value, AttributeValueType};
+use crate::dynamo::key_schema::{
+    expand_key_schema, validate_and_sort_key_schemas, validate_local_secondary_index, KeySchema,
+};
+use crate::dynamo::name::validate_table_name;
+use crate::table::index::{
+    get_index_key_schemas, resolve_secondary_indexes, IndexKeySchemas, SecondaryIndexKind,
+};
+use crate::table::key::{expand_index_key_structs, expand_key_struct};
+use crate::table::parser::parse_from_dynamo_attrs;
+use crate::table::projection::expand_projection_struct;
+use crate::table::settings::{
+    expand_enable_point_in_time_recovery_fn, expand_sse_specification, expand_table_class,
+};
+use crate::table::stream::{expand_stream_specification, expand_stream_view_type};
+use crate::table::tags::PRIMARY_KEY_INPUT_STRUCT_POSTFIX;
+use crate::table::throughput::{
+    expand_billing_mode, expand_on_demand_throughput, expand_provisioned_throughput,
+    resolve_billing_mode,
+};
+use crate::util::to_pascal_case;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use syn::spanned::Spanned;
+use syn::{Data, DataStruct, DeriveInput, Error, LitStr, Result};
+
+/// limit of local secondary indexes per table
+const MAX_LOCAL_SECONDARY_INDEXES: usize = 5;
+/// limit of global secondary indexes per table
+const MAX_GLOBAL_SECONDARY_INDEXES: usize = 20;
+
+pub fn expand_table(input: &mut DeriveInput) -> Result<TokenStream> {
+    let input_span = input.span();
+
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input;
+
+    let struct_attrs = parse_struct_attrs(attrs, TABLE_STRUCT_KEYS)?;
+    let table_name = get_table_name(ident, &struct_attrs)?;
+    let ds = match &data {
+        Data::Struct(ds) => ds,
+        _ => return Err(Error::new(input.span(), "only struct type available")),
+    };
+
+    let to_attribute_ident = quote! { value };
+    let from_attribute_ident = quote! { value };
+    let attribute_types_containers =
+        get_attribute_types_containers(ds, &to_attribute_ident, &from_attribute_ident)?;
+    validate_attribute_definitions(&attribute_types_containers)?;
+    validate_index_names(&attribute_types_containers)?;
+
+    let primary_key_struct_name = format_ident!("{ident}{PRIMARY_KEY_INPUT_STRUCT_POSTFIX}");
+    let primary_key_containers = attribute_types_containers
+        .iter()
+        .filter(|c| !c.key_schemas.is_empty())
+        .collect::<Vec<_>>();
+    let mut prelude_structs = vec![expand_key_struct(
+        vis,
+        ident,
+        generics,
+        &primary_key_struct_name,
+        &primary_key_containers,
+        &struct_attrs,
+    )];
+    prelude_structs.extend(expand_index_key_structs(
+        vis,
+        ident,
+        generics,
+        &primary_key_struct_name,
+        &attribute_types_containers,
+        &struct_attrs,
+        input_span,
+    )?);
+
+    let lsi_key_schemas = get_index_key_schemas(
+        &attribute_types_containers,
+        SecondaryIndexKind::Local,
+        input_span,
+    )?;
+    validate_local_secondary_indexes(&lsi_key_schemas, &attribute_types_containers, input_span)?;
+    let gsi_key_schemas = get_index_key_schemas(
+        &attribute_types_containers,
+        SecondaryIndexKind::Global,
+        input_span,
+    )?;
+    validate_global_secondary_indexes(&gsi_key_schemas, input_span)?;
+    let local_secondary_indexes = resolve_secondary_indexes(
+        SecondaryIndexKind::Local,
+        lsi_key_schemas.clone(),
+        &struct_attrs,
+        &attribute_types_containers,
+    )?;
+    let global_secondary_indexes = resolve_secondary_indexes(
+        SecondaryIndexKind::Global,
+        gsi_key_schemas.clone(),
+        &struct_attrs,
+        &attribute_types_containers,
+    )?;
+    for index in local_secondary_indexes
+        .iter()
+        .chain(&global_secondary_indexes)
+    {
+        prelude_structs.push(expand_projection_struct(
+            vis,
+            ident,
+            generics,
+            ds,
+            index,
+            &attribute_types_containers,
+            &struct_attrs,
+        )?);
+    }
+    let local_secondary_index_token_stream = local_secondary_indexes
+        .iter()
+        .map(|index| index.expand())
+        .collect::<Vec<_>>();
+    let global_secondary_index_token_stream = global_secondary_indexes
+        .iter()
+        .map(|index| index.expand())
+        .collect::<Vec<_>>();
+
+    // expands functions
+    let (
+        stream_view_type_const,
+        get_table_name_fn,
+        create_table_fn,
+        local_secondary_index_key_schemas_fn,
+        global_secondary_index_key_schemas_fn,
+        local_secondary_indexes_fn,
+        global_secondary_indexes_fn,
+        from_attribute_value_fn,
+        put_item_fn,
+        into_put_item_fn,
+        try_put_item_fn,
+        get_primary_keys_fn,
+        primary_key_fn,
+        enable_point_in_time_recovery_fn,
+        conversions,
+    ) = (
+        expand_stream_view_type_const(&struct_attrs),
+        expand_get_table_name_fn(&table_name),
+        expand_create_table_fn(
+            &attribute_types_containers,
+            &table_name,
+            &struct_attrs,
+            &local_secondary_index_token_stream,
+            &global_secondary_index_token_stream,
+            input_span,
+        )?,
+        expand_local_secondary_index_key_schemas_fn(&lsi_key_schemas),
+        expand_global_secondary_index_key_schemas_fn(&gsi_key_schemas),
+        expand_local_secondary_indexes_fn(&local_secondary_index_token_stream),
+        expand_global_secondary_indexes_fn(&global_secondary_index_token_stream),
+        expand_from_attribute_value_fn(&from_attribute_ident),
+        expand_put_item_fn(&table_name),
+        expand_into_put_item_fn(&table_name),
+        expand_try_put_item_fn(&table_name),
+        expand_get_primary_keys_fn(&primary_key_struct_name),
+        expand_primary_key_fn(&primary_key_struct_name),
+        struct_attrs.point_in_time_recovery.then(|| {
+            let enable_point_in_time_recovery_fn =
+                expand_enable_point_in_time_recovery_fn(&table_name);
+            quote! { #vis #enable_point_in_time_recovery_fn }
+        }),
+        container::expand_impl_conversions(
+            vis,
+            ident,
+            generics,
+            &attribute_types_containers,
+            &struct_attrs,
+        )?,
+    );
+
+    let encode_generics = with_encode_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = encode_generics.split_for_impl();
+
+    Ok(quote! {
+        #( #prelude_structs )*
+
+        #(
+            #[allow(clippy::needless_question_mark)]
+            #conversions
+        )*
+
+        #[allow(clippy::map_clone)]
+        #[allow(clippy::needless_question_mark)]
+        #[allow(dead_code)]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #vis #stream_view_type_const
+            #vis #get_table_name_fn
+            #vis #create_table_fn
+            #vis #local_secondary_index_key_schemas_fn
+            #vis #global_secondary_index_key_schemas_fn
+            #vis #local_secondary_indexes_fn
+            #vis #global_secondary_indexes_fn
+            #vis #from_attribute_value_fn
+            #vis #put_item_fn
+            #vis #into_put_item_fn
+            #vis #try_put_item_fn
+            #vis #get_primary_keys_fn
+            #vis #primary_key_fn
+            #enable_point_in_time_recovery_fn
+        }
+    })
+}
+
+fn get_table_name(id: &Ident, struct_attrs: &StructAttrs) -> Result<LitStr> {
+    let table_name = struct_attrs
+        .table_name
+        .clone()
+        .unwrap_or_else(|| LitStr::new(&to_pascal_case(&id.to_string()), id.span()));
+    validate_table_name(&table_name.value(), table_name.span())?;
+    Ok(table_name)
+}
+
+/// fields whose names convert into the same attribute name would define the attribute twice,
+/// possibly with conflicting types across the table and its indexes
+fn validate_attribute_definitions(containers: &[Container]) -> Result<()> {
+    let mut definitions = BTreeMap::<String, (&Ident, ScalarAttributeType)>::new();
+    for container in containers {
+        let ident = container.field_ident;
+        let name = to_pascal_case(&ident.to_string());
+        for ty in &container.attribute_definitions {
+            match definitions.get(&name) {
+                Some((other, other_ty)) if other_ty != ty => {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("attribute `{name}` is defined as {ty:?} by `{ident}` and as {other_ty:?} by `{other}`"),
+                    ))
+                }
+                Some((other, _)) => {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("attribute `{name}` is defined by both `{ident}` and `{other}`"),
+                    ))
+                }
+                None => {
+                    definitions.insert(name.clone(), (ident, *ty));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// index names are unique per table, whether the index is local or global
+fn validate_index_names(containers: &[Container]) -> Result<()> {
+    for container in containers {
+        for (index_name, key_schemas) in &container.global_secondary_index_key_schemas {
+            if containers
+                .iter()
+                .any(|c| c.local_secondary_index_key_schemas.contains_key(index_name))
+            {
+                let span = key_schemas
+                    .first()
+                    .map(|ks| ks.span)
+                    .unwrap_or(container.field_ident.span());
+                return Err(Error::new(
+                    span,
+                    format!("index `{index_name}` is declared as both a local and a global secondary index"),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_attribute_types_containers<'a>(
+    ds: &'a DataStruct,
+    to_attribute_ident: &'a TokenStream,
+    from_attribute_ident: &'a TokenStream,
+) -> Result<Vec<Container<'a>>> {
+    let mut containers = vec![];
+
+    for field in &ds.fields {
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or(Error::new(field.ident.span(), "field ident not found"))?;
+        let ty = &field.ty;
+        let mut container = Container::new(ident, ty, to_attribute_ident);
+        container.conversion = parse_field_conversion(&field.attrs)?;
+        if parse_other_attr(&field.attrs)? {
+            validate_other_type(ty)?;
+            reject_other_conversion(&container)?;
+            container.other = true;
+            parse_from_dynamo_attrs(&field.attrs, field, AttributeValueType::M, &mut container)?;
+            containers.push(container);
+            continue;
+        }
+
+        let (mut container, attribute_value_type) =
+            expand_attribute_value(from_attribute_ident, container)?;
+
+        parse_from_dynamo_attrs(&field.attrs, field, attribute_value_type, &mut container)?;
+
+        containers.push(container);
+    }
+
+    Ok(containers)
+}
+
+fn expand_stream_view_type_const(struct_attrs: &StructAttrs) -> TokenStream {
+    let stream_view_type = match struct_attrs.stream {
+        Some(stream_view_type) => {
+            let stream_view_type = expand_stream_view_type(stream_view_type);
+            quote! { ::std::option::Option::Some(#stream_view_type) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
+    quote! {
+        const STREAM_VIEW_TYPE: ::std::option::Option<::aws_sdk_dynamodb::types::StreamViewType> =
+            #stream_view_type;
+    }
+}
+
+fn expand_get_table_name_fn(table_name: &LitStr) -> TokenStream {
+    quote! {
+        fn get_table_name() -> &'static ::std::primitive::str {
+            #table_name
+        }
+    }
+}
+
+fn expand_create_table_fn(
+    containers: &[Container],
+    table_name: &LitStr,
+    struct_attrs: &StructAttrs,
+    local_secondary_indexes: &[TokenStream],
+    global_secondary_indexes: &[TokenStream],
+    span: Span,
+) -> Result<TokenStream> {
+    let attribute_definitions_token_stream = containers
+        .iter()
+        .flat_map(|c| {
+            c.attribute_definitions
+                .iter()
+                .map(|ad| ad.expand_attribute_definition(c.field_ident))
+        })
+        .collect::<Vec<_>>();
+
+    let key_schemas = get_table_key_schemas(containers, span)?;
+    let key_schema_token_stream = key_schemas
+        .into_iter()
+        .map(|(ident, ks)| expand_key_schema(ident, ks.key_type))
+        .collect::<Vec<_>>();
+
+    let billing_mode = resolve_billing_mode(struct_attrs)?.map(|billing_mode| {
+        let billing_mode = expand_billing_mode(billing_mode);
+        quote! { .billing_mode(#billing_mode) }
+    });
+    let provisioned_throughput = struct_attrs
+        .provisioned_throughput
+        .as_ref()
+        .map(|throughput| {
+            let throughput = expand_provisioned_throughput(throughput);
+            quote! { .provisioned_throughput(#throughput) }
+        });
+    let on_demand_throughput = struct_attrs
+        .on_demand_throughput
+        .as_ref()
+        .map(|throughput| {
+            let throughput = expand_on_demand_throughput(throughput);
+            quote! { .on_demand_throughput(#throughput) }
+        });
+    let stream_specification = struct_attrs.stream.map(|stream_view_type| {
+        let stream_specification = expand_stream_specification(stream_view_type);
+        quote! { .stream_specification(#stream_specification) }
+    });
+    let table_class = struct_attrs.table_class.map(|table_class| {
+        let table_class = expand_table_class(table_class);
+        quote! { .table_class(#table_class) }
+    });
+    let deletion_protection = struct_attrs
+        .deletion_protection
+        .then(|| quote! { .deletion_protection_enabled(true) });
+    let sse_specification = struct_attrs.sse.as_ref().map(|sse| {
+        let sse_specification = expand_sse_specification(sse);
+        quote! { .sse_specification(#sse_specification) }
+    });
+
+    Ok(quote! {
+        fn create_table(
+                mut builder: ::aws_sdk_dynamodb::operation::create_table::builders::CreateTableFluentBuilder
+            ) -> ::aws_sdk_dynamodb::operation::create_table::builders::CreateTableFluentBuilder {
+                builder
+                    .table_name(#table_name)
+                    #( .attribute_definitions(#attribute_definitions_token_stream) )*
+                    #( .key_schema(#key_schema_token_stream) )*
+                    #( .local_secondary_indexes(#local_secondary_indexes) )*
+                    #( .global_secondary_indexes(#global_secondary_indexes) )*
+                    #billing_mode
+                    #provisioned_throughput
+                    #on_demand_throughput
+                    #stream_specification
+                    #table_class
+                    #deletion_protection
+                    #sse_specification
+            }
+    })
+}
+
+/// key schemas of the table, validated and sorted
+fn get_table_key_schemas<'a>(
+    containers: &[Container<'a>],
+    span: Span,
+) -> Result<Vec<(&'a Ident, KeySchema)>> {
+    let mut key_schemas = containers
+        .iter()
+        .flat_map(|c| c.key_schemas.iter().map(|ks| (c.field_ident, *ks)))
+        .collect::<Vec<_>>();
+
+    validate_and_sort_key_schemas(&mut key_schemas, span, None)?;
+
+    Ok(key_schemas)
+}
+
+fn validate_local_secondary_indexes(
+    lsi_key_schemas: &IndexKeySchemas,
+    containers: &[Container],
+    span: Span,
+) -> Result<()> {
+    if let Some((index_name, key_schemas)) = lsi_key_schemas.iter().nth(MAX_LOCAL_SECONDARY_INDEXES)
+    {
+        let span = key_schemas.first().map(|(_, ks)| ks.span).unwrap_or(span);
+        return Err(Error::new(
+            span,
+            format!(
+                "at most {MAX_LOCAL_SECONDARY_INDEXES} local secondary indexes are allowed per table, found `{index_name}` over the limit"
+            ),
+        ));
+    }
+
+    let table_key_schemas = get_table_key_schemas(containers, span)?;
+    lsi_key_schemas.iter().try_for_each(|(index_name, v)| {
+        validate_local_secondary_index(index_name, v, &table_key_schemas, span)
+    })
+}
+
+fn validate_global_secondary_indexes(gsi_key_schemas: &IndexKeySchemas, span: Span) -> Result<()> {
+    if let Some((index_name, key_schemas)) =
+        gsi_key_schemas.iter().nth(MAX_GLOBAL_SECONDARY_INDEXES)
+    {
+        let span = key_schemas.first().map(|(_, ks)| ks.span).unwrap_or(span);
+        return Err(Error::new(
+            span,
+            format!(
+                "at most {MAX_GLOBAL_SECONDARY_INDEXES} global secondary indexes are allowed per table, found `{index_name}` over the limit"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn expand_local_secondary_index_key_schemas_fn(lsi_key_schemas: &IndexKeySchemas) -> TokenStream {
+    let key_schema_token_stream = lsi_key_schemas
+        .iter()
+        .flat_map(|(index_name, ks)| {
+            ks.iter().map(move |(ident, key_schema)| {
+                let lsi_key_schemas_token = expand_key_schema(ident, key_schema.key_type);
+                quote! {
+                    lsi_key_schemas.entry(#index_name.to_string()).or_default().push(#lsi_key_schemas_token);
+                }
+            })
+        }).collect::<Vec<_>>();
+
+    quote! {
+        fn get_local_secondary_index_key_schemas()
+            -> ::std::collections::HashMap<::std::string::String, Vec<::aws_sdk_dynamodb::types::KeySchemaElement>> {
+            let mut lsi_key_schemas: std::collections::HashMap<
+                String, Vec<aws_sdk_dynamodb::types::KeySchemaElement>
+            > = std::collections::HashMap::new();
+            #( #key_schema_token_stream )*;
+            lsi_key_schemas
+        }
+    }
+}
+
+fn expand_global_secondary_index_key_schemas_fn(gsi_key_schemas: &IndexKeySchemas) -> TokenStream {
+    let key_schema_token_stream = gsi_key_schemas
+        .iter()
+        .flat_map(|(index_name, ks)| {
+            ks.iter().map(move |(ident, key_schema)| {
+                let gsi_key_schemas_token = expand_key_schema(ident, key_schema.key_type);
+                quote! {
+                    gsi_key_schemas.entry(#index_name.to_string()).or_default().push(#gsi_key_schemas_token);
+                }
+            })
+        }).collect::<Vec<_>>();
+
+    quote! {
+        fn get_global_secondary_index_key_schemas()
+            -> ::std::collections::HashMap<::std::string::String, Vec<::aws_sdk_dynamodb::types::KeySchemaElement>> {
+            let mut gsi_key_schemas: std::collections::HashMap<
+                String, Vec<aws_sdk_dynamodb::types::KeySchemaElement>
+            > = std::collections::HashMap::new();
+            #( #key_schema_token_stream )*;
+            gsi_key_schemas
+        }
+    }
+}
+
+fn expand_local_secondary_indexes_fn(local_secondary_indexes: &[TokenStream]) -> TokenStream {
+    quote! {
+        fn get_local_secondary_indexes() -> Vec<::aws_sdk_dynamodb::types::LocalSecondaryIndex> {
+            vec![ #( #local_secondary_indexes ),* ]
+        }
+    }
+}
+
+fn expand_global_secondary_indexes_fn(global_secondary_indexes: &[TokenStream]) -> TokenStream {
+    quote! {
+        fn get_global_secondary_indexes() -> Vec<::aws_sdk_dynamodb::types::GlobalSecondaryIndex> {
+            vec![ #( #global_secondary_indexes ),* ]
+        }
+    }
+}
+
+fn expand_from_attribute_value_fn(from_attribute_ident: &TokenStream) -> TokenStream {
+    let de = decode_lifetime();
+    quote! {
+        fn from_attribute_value<#de>(
+            #from_attribute_ident: &#de ::std::collections::HashMap<
+            ::std::string::String,
+            ::aws_sdk_dynamodb::types::AttributeValue>
+        ) -> Result<Self, ::aws_dynamo_derive::DynamoConversionError>
+        where
+            Self: ::aws_dynamo_derive::__private::FromAttributeMap<#de>,
+        {
+            ::aws_dynamo_derive::__private::FromAttributeMap::from_attribute_map(#from_attribute_ident)
+        }
+    }
+}
+
+fn expand_put_item_fn(table_name: &LitStr) -> TokenStream {
+    quote! {
+        fn put_item(
+            &self,
+            builder: ::aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder
+        ) -> aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder {
+            ::aws_dynamo_derive::__private::put_item_attributes(
+                builder.table_name(#table_name),
+                ::aws_dynamo_derive::__private::ToAttributeMap::to_attribute_map(self),
+            )
+        }
+    }
+}
+
+fn expand_into_put_item_fn(table_name: &LitStr) -> TokenStream {
+    quote! {
+        fn into_put_item(
+            self,
+            builder: ::aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder
+        ) -> aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder {
+            ::aws_dynamo_derive::__private::put_item_attributes(
+                builder.table_name(#table_name),
+                ::aws_dynamo_derive::__private::ToAttributeMap::into_attribute_map(self),
+            )
+        }
+    }
+}
+
+fn expand_try_put_item_fn(table_name: &LitStr) -> TokenStream {
+    quote! {
+        fn try_put_item(
+            &self,
+            builder: ::aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder
+        ) -> Result<
+            ::aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder,
+            ::aws_dynamo_derive::DynamoConversionError
+        > {
+            Ok(::aws_dynamo_derive::__private::put_item_attributes(
+                builder.table_name(#table_name),
+                self.try_to_attribute_value()?,
+            ))
+        }
+    }
+}
+
+fn expand_get_primary_keys_fn(primary_key_struct_name: &Ident) -> TokenStream {
+    quote! {
+        fn get_primary_keys(input: #primary_key_struct_name)
+        -> ::std::collections::HashMap<
+            ::std::string::String, ::aws_sdk_dynamodb::types::AttributeValue>
+        {
+            input.into()
+        }
+    }
+}
+
+fn expand_primary_key_fn(primary_key_struct_name: &Ident) -> TokenStream {
+    quote! {
+        fn primary_key(&self) -> #primary_key_struct_name {
+            self.into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_table {
+    use crate::table::expand_table;
+
+    use syn::DeriveInput;
+
+    /// expands `input` and returns the error message with the line and column it points at
+    fn expand_error(input: &str) -> (String, usize, usize) {
+        let mut input = syn::parse_str::<DeriveInput>(input).unwrap();
+        let error = expand_table(&mut input).unwrap_err();
+        let start = error.span().start();
+        (error.to_string(), start.line, start.column)
+    }
+
+    #[test]
+    fn duplicated_keys() {
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+            #[aws_dynamo(hash_key)]
+            duplicated_hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "only one HashKey is allowed: `duplicated_hash_key` conflicts with `hash_key`"
+                    .to_string(),
+                4,
+                25
+            )
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+            #[aws_dynamo(range_key)]
+            range_key: u32,
+            #[aws_dynamo(range_key)]
+            duplicated_range_key: u32,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "at most one RangeKey is allowed: `duplicated_range_key` conflicts with `range_key`"
+                    .to_string(),
+                6,
+                25
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_field_attributes() {
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_kye)]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "unknown aws_dynamo attribute `hash_kye`, did you mean `hash_key`?".to_string(),
+                2,
+                25
+            )
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key, range_key)]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            ("`range_key` conflicts with `hash_key`".to_string(), 2, 35)
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+            #[aws_dynamo(other)]
+            extra: BTreeMap<String, AttributeValue>,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "`other` field must be `HashMap<String, AttributeValue>`".to_string(),
+                5,
+                19
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_indexes() {
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            #[aws_dynamo(global_secondary_index(index_name = "gsi-1", hash_key))]
+            #[aws_dynamo(global_secondary_index(index_name = "gsi_1", hash_key))]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "indexes `gsi-1` and `gsi_1` have the same key struct `TableGsi1Key`".to_string(),
+                1,
+                0
+            )
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+            #[aws_dynamo(range_key)]
+            range_key: String,
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+            other_hash_key: String,
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+            lsi_range_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "HashKey for local secondary index `lsi1` must be the table HashKey `hash_key`"
+                    .to_string(),
+                6,
+                68
+            )
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+            hash_key: String,
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+            lsi_range_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "a RangeKey is required on the table for local secondary index `lsi1`".to_string(),
+                3,
+                68
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_names() {
+        let input = r#"#[aws_dynamo(table_name = "Foo Table")]
+        struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "table name `Foo Table` contains ` `, only `a-z`, `A-Z`, `0-9`, `_`, `-` and `.` are allowed"
+                    .to_string(),
+                1,
+                26
+            )
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            #[aws_dynamo(global_secondary_index(index_name = "ix", hash_key))]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "index name `ix` must be 3 to 255 characters long".to_string(),
+                3,
+                61
+            )
+        );
+
+        let input = r#"struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+            #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+            HashKey: u32,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "attribute `HashKey` is defined as N by `HashKey` and as S by `hash_key`"
+                    .to_string(),
+                5,
+                12
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_throughput() {
+        let input = r#"#[aws_dynamo(billing_mode = "provisioned")]
+        struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "billing_mode `provisioned` requires `provisioned_throughput` on the table"
+                    .to_string(),
+                1,
+                28
+            )
+        );
+
+        let input = r#"#[aws_dynamo(
+            billing_mode = "pay_per_request",
+            provisioned_throughput(read_capacity_units = 1, write_capacity_units = 1)
+        )]
+        struct Table {
+            #[aws_dynamo(hash_key)]
+            hash_key: String,
+        }"#;
+        assert_eq!(
+            expand_error(input),
+            (
+                "`provisioned_throughput` conflicts with billing_mode `pay_per_request`"
+                    .to_string(),
+                3,
+                12
+            )
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::attrs::{OnDemandThroughput, ProjectionType, ProvisionedThroughput, StructAttrs};
+use crate::container::Container;
+use crate::dynamo::key_schema::{expand_key_schema, validate_and_sort_key_schemas, KeySchema};
+use crate::table::throughput::{expand_on_demand_throughput, expand_provisioned_throughput};
+use crate::util::{did_you_mean, to_pascal_case};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::{Error, Result};
+
+/// key schemas of each secondary index by index name, validated and sorted
+pub type IndexKeySchemas<'a> = BTreeMap<String, Vec<(&'a Ident, KeySchema)>>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SecondaryIndexKind {
+    Local,
+    Global,
+}
+
+impl SecondaryIndexKind {
+    fn key_schemas<'c>(self, container: &'c Container) -> &'c BTreeMap<String, Vec<KeySchema>> {
+        match self {
+            Self::Local => &container.local_secondary_index_key_schemas,
+            Self::Global => &container.global_secondary_index_key_schemas,
+        }
+    }
+
+    fn projections<'c>(self, container: &'c Container) -> &'c BTreeSet<String> {
+        match self {
+            Self::Local => &container.local_secondary_index_projections,
+            Self::Global => &container.global_secondary_index_projections,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Local => "local secondary index",
+            Self::Global => "global secondary index",
+        }
+    }
+}
+
+/// collects the key schemas declared on fields for each index of the kind
+pub fn get_index_key_schemas<'a>(
+    containers: &[Container<'a>],
+    kind: SecondaryIndexKind,
+    span: Span,
+) -> Result<IndexKeySchemas<'a>> {
+    let mut index_key_schemas = IndexKeySchemas::new();
+    for container in containers {
+        for (index_name, key_schemas) in kind.key_schemas(container) {
+            index_key_schemas
+                .entry(index_name.clone())
+                .or_default()
+                .extend(key_schemas.iter().map(|ks| (container.field_ident, *ks)));
+        }
+    }
+
+    index_key_schemas
+        .iter_mut()
+        .try_for_each(|(index_name, v)| validate_and_sort_key_schemas(v, span, Some(index_name)))?;
+
+    Ok(index_key_schemas)
+}
+
+/// secondary index with the attributes it projects
+pub struct SecondaryIndex<'a> {
+    pub kind: SecondaryIndexKind,
+    pub index_name: String,
+    pub key_schemas: Vec<(&'a Ident, KeySchema)>,
+    pub projection: ProjectionType,
+    pub non_key_attributes: Vec<String>,
+    /// throughput of a GSI, declared on the index or inherited from the table
+    pub provisioned_throughput: Option<ProvisionedThroughput>,
+    pub on_demand_throughput: Option<OnDemandThroughput>,
+}
+
+impl SecondaryIndex<'_> {
+    /// whether items read from the index hold the field.
+    /// The catch-all `other` field is kept to receive non-key attributes not bound to fields.
+    pub fn projects(&self, container: &Container) -> bool {
+        self.projection == ProjectionType::All
+            || container.other
+            || !container.key_schemas.is_empty()
+            || self
+                .kind
+                .key_schemas(container)
+                .contains_key(&self.index_name)
+            || self
+                .non_key_attributes
+                .contains(&to_pascal_case(&container.field_ident.to_string()))
+    }
+
+    /// expands the `LocalSecondaryIndex` or `GlobalSecondaryIndex`
+    pub fn expand(&self) -> TokenStream {
+        let index_name = &self.index_name;
+        let non_key_attributes = &self.non_key_attributes;
+        let index_type = match self.kind {
+            SecondaryIndexKind::Local => quote! { ::aws_sdk_dynamodb::types::LocalSecondaryIndex },
+            SecondaryIndexKind::Global => {
+                quote! { ::aws_sdk_dynamodb::types::GlobalSecondaryIndex }
+            }
+        };
+        let projection_type = match self.projection {
+            ProjectionType::All => quote! { All },
+            ProjectionType::KeysOnly => quote! { KeysOnly },
+            ProjectionType::Include => quote! { Include },
+        };
+        let key_schema_token_stream = self
+            .key_schemas
+            .iter()
+            .map(|(ident, ks)| expand_key_schema(ident, ks.key_type))
+            .collect::<Vec<_>>();
+        let provisioned_throughput = self.provisioned_throughput.as_ref().map(|throughput| {
+            let throughput = expand_provisioned_throughput(throughput);
+            quote! { .provisioned_throughput(#throughput) }
+        });
+        let on_demand_throughput = self.on_demand_throughput.as_ref().map(|throughput| {
+            let throughput = expand_on_demand_throughput(throughput);
+            quote! { .on_demand_throughput(#throughput) }
+        });
+
+        quote! {
+            #index_type::builder()
+                .index_name(#index_name)
+                #( .key_schema(#key_schema_token_stream) )*
+                .projection(
+                    ::aws_sdk_dynamodb::types::Projection::builder()
+                        .projection_type(::aws_sdk_dynamodb::types::ProjectionType::#projection_type)
+                        #( .non_key_attributes(#non_key_attributes) )*
+                        .build()
+                )
+                #provisioned_throughput
+                #on_demand_throughput
+                .build()
+                .unwrap()
+        }
+    }
+}
+
+/// resolves the projection of each index from the attributes configured on top of the struct
+/// and the fields marked with `project`.
+/// Without a `projection`, an index projects `include` if it has non-key attributes and `all` otherwise.
+/// A GSI takes the throughput of the table unless it declares its own.
+pub fn resolve_secondary_indexes<'a>(
+    kind: SecondaryIndexKind,
+    index_key_schemas: IndexKeySchemas<'a>,
+    struct_attrs: &StructAttrs,
+    containers: &[Container],
+) -> Result<Vec<SecondaryIndex<'a>>> {
+    let index_attrs = match kind {
+        SecondaryIndexKind::Local => &struct_attrs.local_secondary_indexes,
+        SecondaryIndexKind::Global => &struct_attrs.global_secondary_indexes,
+    };
+    let index_names = index_key_schemas
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    let unknown_index = |index_name: &str| match did_you_mean(index_name, &index_names) {
+        Some(suggestion) => format!(
+            "unknown {} `{index_name}`, did you mean `{suggestion}`?",
+            kind.name()
+        ),
+        None => format!("unknown {} `{index_name}`", kind.name()),
+    };
+
+    for index in index_attrs {
+        if !index_key_schemas.contains_key(&index.index_name.value()) {
+            return Err(Error::new(
+                index.index_name.span(),
+                unknown_index(&index.index_name.value()),
+            ));
+        }
+    }
+    for container in containers {
+        for index_name in kind.projections(container) {
+            let ident = container.field_ident;
+            if !index_key_schemas.contains_key(index_name) {
+                return Err(Error::new(ident.span(), unknown_index(index_name)));
+            }
+            if !container.key_schemas.is_empty()
+                || kind.key_schemas(container).contains_key(index_name)
+            {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("`{ident}` is a key attribute, which `{index_name}` always projects"),
+                ));
+            }
+        }
+    }
+
+    index_key_schemas
+        .into_iter()
+        .map(|(index_name, key_schemas)| {
+            let index = index_attrs
+                .iter()
+                .find(|index| index.index_name.value() == index_name);
+
+            let mut non_key_attributes = index
+                .map(|index| {
+                    index
+                        .non_key_attributes
+                        .iter()
+                        .map(|lit| lit.value())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            for container in containers {
+                let name = to_pascal_case(&container.field_ident.to_string());
+                if kind.projections(container).contains(&index_name)
+                    && !non_key_attributes.contains(&name)
+                {
+                    non_key_attributes.push(name);
+                }
+            }
+
+            let projection = index.and_then(|index| Some((index.projection?, index.index_name.span())));
+            let projection = match projection {
+                Some((projection @ ProjectionType::Include, span)) if non_key_attributes.is_empty() => {
+                    return Err(Error::new(
+                        span,
+                        format!("projection `{projection}` of `{index_name}` requires non-key attributes"),
+                    ))
+                }
+                Some((projection @ (ProjectionType::All | ProjectionType::KeysOnly), span))
+                    if !non_key_attributes.is_empty() =>
+                {
+                    return Err(Error::new(
+                        span,
+                        format!("projection `{projection}` of `{index_name}` takes no non-key attributes"),
+                    ))
+                }
+                Some((projection, _)) => projection,
+                None if non_key_attributes.is_empty() => ProjectionType::All,
+                None => ProjectionType::Include,
+            };
+
+            let (provisioned_throughput, on_demand_throughput) = match kind {
+                SecondaryIndexKind::Local => (None, None),
+                SecondaryIndexKind::Global => (
+                    index
+                        .and_then(|index| index.provisioned_throughput)
+                        .or(struct_attrs.provisioned_throughput),
+                    index
+                        .and_then(|index| index.on_demand_throughput)
+                        .or(struct_attrs.on_demand_throughput),
+                ),
+            };
+
+            Ok(SecondaryIndex {
+                kind,
+                index_name,
+                key_schemas,
+                projection,
+                non_key_attributes,
+                provisioned_throughput,
+                on_demand_throughput,
+            })
+        })
+        .collect()
+}
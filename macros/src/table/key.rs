@@ -0,0 +1,239 @@
+use crate::attrs::StructAttrs;
+use crate::container::{expand_options, Container};
+use crate::dynamo::attribute_definition::ScalarAttributeType;
+use crate::table::tags::INDEX_KEY_STRUCT_POSTFIX;
+use crate::util::to_pascal_case;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::{Error, Generics, Result, Type, Visibility};
+
+/// expands a key struct per local and global secondary index, holding the index keys
+/// and the table keys, which DynamoDB returns as the `LastEvaluatedKey` of index queries
+pub fn expand_index_key_structs(
+    vis: &Visibility,
+    struct_name: &Ident,
+    generics: &Generics,
+    primary_key_struct_name: &Ident,
+    containers: &[Container],
+    struct_attrs: &StructAttrs,
+    span: Span,
+) -> Result<Vec<TokenStream>> {
+    let index_names = containers
+        .iter()
+        .flat_map(|c| {
+            c.local_secondary_index_key_schemas
+                .keys()
+                .chain(c.global_secondary_index_key_schemas.keys())
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut key_struct_names = BTreeMap::new();
+    let mut key_structs = vec![];
+    for index_name in index_names {
+        let key_struct_name = index_struct_name(struct_name, index_name, INDEX_KEY_STRUCT_POSTFIX);
+        if key_struct_name == *primary_key_struct_name {
+            return Err(Error::new(
+                span,
+                format!("key struct `{key_struct_name}` of index `{index_name}` conflicts with the primary key struct"),
+            ));
+        }
+        if let Some(other) = key_struct_names.insert(key_struct_name.to_string(), index_name) {
+            return Err(Error::new(
+                span,
+                format!("indexes `{other}` and `{index_name}` have the same key struct `{key_struct_name}`"),
+            ));
+        }
+
+        let index_containers = containers
+            .iter()
+            .filter(|c| {
+                !c.key_schemas.is_empty()
+                    || c.local_secondary_index_key_schemas.contains_key(index_name)
+                    || c.global_secondary_index_key_schemas
+                        .contains_key(index_name)
+            })
+            .collect::<Vec<_>>();
+        key_structs.push(expand_key_struct(
+            vis,
+            struct_name,
+            generics,
+            &key_struct_name,
+            &index_containers,
+            struct_attrs,
+        ));
+    }
+
+    Ok(key_structs)
+}
+
+/// `gsi1` of `FooTable` is `FooTableGsi1Key`, or `FooTableGsi1Projection` with the projection postfix.
+/// Characters not allowed in identifiers separate words like `_`.
+pub fn index_struct_name(struct_name: &Ident, index_name: &str, postfix: &str) -> Ident {
+    let index_name = index_name
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '_' })
+        .collect::<String>();
+    format_ident!("{struct_name}{}{postfix}", to_pascal_case(&index_name))
+}
+
+/// expands a struct holding the key fields of the table or an index,
+/// with conversions from the item and to and from attribute maps
+pub fn expand_key_struct(
+    vis: &Visibility,
+    struct_name: &Ident,
+    generics: &Generics,
+    key_struct_name: &Ident,
+    containers: &[&Container],
+    struct_attrs: &StructAttrs,
+) -> TokenStream {
+    let options = expand_options(struct_attrs);
+    let from_item = expand_key_from_item(struct_name, generics, key_struct_name, containers);
+
+    let fields = containers
+        .iter()
+        .map(|c| {
+            let ident = c.field_ident;
+            let ty = owned_key_type(c.ty);
+            quote! { pub #ident: #ty }
+        })
+        .collect::<Vec<_>>();
+
+    let map_inserts = containers
+        .iter()
+        .map(|c| {
+            let ident = c.field_ident;
+            let key = to_pascal_case(&ident.to_string());
+            let to_attribute = match &c.conversion.with {
+                Some(with) => quote! { #with::to_attribute_value(&value.#ident) },
+                None => quote! {
+                    ::aws_dynamo_derive::__private::ToAttributeValue::into_attribute_value(
+                        value.#ident, __private_options
+                    )
+                },
+            };
+            quote! {
+                map.insert(#key.to_string(), #to_attribute);
+            }
+        })
+        .collect::<Vec<_>>();
+    let field_count = containers.len();
+
+    let decode_fields = containers
+        .iter()
+        .map(|c| {
+            let ident = c.field_ident;
+            let key = to_pascal_case(&ident.to_string());
+            let decode = match &c.conversion.with {
+                Some(with) => quote! {
+                    ::aws_dynamo_derive::__private::decode_field_with(
+                        value.get(#key), #with::from_attribute_value
+                    )
+                },
+                None => quote! {
+                    ::aws_dynamo_derive::__private::decode_field(value.get(#key), __private_options)
+                },
+            };
+            quote! {
+                #ident: ::aws_dynamo_derive::__private::with_key::<
+                    _,
+                    ::aws_dynamo_derive::DynamoConversionError
+                >(#key, || #decode)?
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // `Blob` is not ordered
+    let ord = containers
+        .iter()
+        .all(|c| !c.attribute_definitions.contains(&ScalarAttributeType::B))
+        .then(|| quote! { Ord, PartialOrd });
+
+    quote! {
+        #[derive(Debug, Clone, Eq, PartialEq, #ord)]
+        #vis struct #key_struct_name {
+            #( #fields, )*
+        }
+
+        #from_item
+
+        impl From<#key_struct_name> for ::std::collections::HashMap<
+            ::std::string::String,
+            ::aws_sdk_dynamodb::types::AttributeValue
+        > {
+            fn from(value: #key_struct_name) -> Self {
+                #options
+                let mut map = ::std::collections::HashMap::with_capacity(#field_count);
+                #( #map_inserts )*
+                map
+            }
+        }
+
+        impl TryFrom<&::std::collections::HashMap<
+            ::std::string::String,
+            ::aws_sdk_dynamodb::types::AttributeValue
+        >> for #key_struct_name {
+            type Error = ::aws_dynamo_derive::DynamoConversionError;
+            fn try_from(value: &::std::collections::HashMap<
+                ::std::string::String,
+                ::aws_sdk_dynamodb::types::AttributeValue
+            >) -> Result<Self, Self::Error> {
+                #options
+                Ok(Self {
+                    #( #decode_fields, )*
+                })
+            }
+        }
+    }
+}
+
+/// expands the conversion from the item, or another struct holding the key fields, into the key struct
+pub fn expand_key_from_item(
+    struct_name: &Ident,
+    generics: &Generics,
+    key_struct_name: &Ident,
+    containers: &[&Container],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let from_item_fields = containers
+        .iter()
+        .map(|c| {
+            let ident = c.field_ident;
+            let owned = expand_owned_key(c.ty, quote! { value.#ident });
+            quote! { #ident: #owned }
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        impl #impl_generics From<&#struct_name #ty_generics> for #key_struct_name #where_clause {
+            fn from(value: &#struct_name #ty_generics) -> Self {
+                Self {
+                    #( #from_item_fields, )*
+                }
+            }
+        }
+    }
+}
+
+/// type of a key field in a key struct, which owns borrowed `&str` and `&[u8]` keys
+fn owned_key_type(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Reference(reference) => match reference.elem.as_ref() {
+            Type::Slice(_) => quote! { ::aws_sdk_dynamodb::primitives::Blob },
+            _ => quote! { ::std::string::String },
+        },
+        _ => ty.to_token_stream(),
+    }
+}
+
+/// copies a key field of the item into its `owned_key_type`
+fn expand_owned_key(ty: &Type, field: TokenStream) -> TokenStream {
+    match ty {
+        Type::Reference(reference) => match reference.elem.as_ref() {
+            Type::Slice(_) => quote! { ::aws_sdk_dynamodb::primitives::Blob::new(#field.to_vec()) },
+            _ => quote! { #field.to_string() },
+        },
+        _ => quote! { #field.clone() },
+    }
+}
@@ -0,0 +1,382 @@
+use crate::attrs::{duplicate_attribute_error, unknown_attribute_error};
+use crate::container::Container;
+use crate::dynamo::attribute_definition::ScalarAttributeType;
+use crate::dynamo::attribute_value::AttributeValueType;
+use crate::dynamo::key_schema::{KeySchema, KeySchemaType};
+use crate::dynamo::name::{validate_index_name, validate_key_attribute_name};
+use crate::tags::{AWS_DYNAMO_ATTR_META_ENTRY, KEY_ATTRIBUTE_TYPE, KEY_OTHER, KEY_WITH};
+use crate::util::{strip_quote_mark, to_pascal_case};
+
+use proc_macro2::Literal;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, Field, LitStr, Result, Type};
+
+const HASH_KEY: &str = "hash_key";
+const RANGE_KEY: &str = "range_key";
+const LOCAL_SECONDARY_INDEX_ENTRY: &str = "local_secondary_index";
+const GLOBAL_SECONDARY_INDEX_ENTRY: &str = "global_secondary_index";
+const SECONDARY_INDEX_NAME: &str = "index_name";
+const SECONDARY_INDEX_PROJECT: &str = "project";
+
+const TABLE_FIELD_KEYS: &[&str] = &[
+    HASH_KEY,
+    RANGE_KEY,
+    LOCAL_SECONDARY_INDEX_ENTRY,
+    GLOBAL_SECONDARY_INDEX_ENTRY,
+    KEY_OTHER,
+    KEY_WITH,
+    KEY_ATTRIBUTE_TYPE,
+];
+const SECONDARY_INDEX_KEYS: &[&str] = &[
+    SECONDARY_INDEX_NAME,
+    HASH_KEY,
+    RANGE_KEY,
+    SECONDARY_INDEX_PROJECT,
+];
+
+pub fn parse_from_dynamo_attrs(
+    attrs: &[Attribute],
+    field: &Field,
+    attribute_value_type: AttributeValueType,
+    container: &mut Container,
+) -> Result<()> {
+    let mut other = false;
+    let converted_with = container.conversion.with.is_some();
+
+    for attr in attrs {
+        if attr.path().is_ident(AWS_DYNAMO_ATTR_META_ENTRY) {
+            attr.parse_nested_meta(|table_meta| {
+                if !TABLE_FIELD_KEYS
+                    .iter()
+                    .any(|key| table_meta.path.is_ident(key))
+                {
+                    return Err(unknown_attribute_error(&table_meta, TABLE_FIELD_KEYS));
+                }
+
+                // parsed by `parse_field_conversion`
+                if table_meta.path.is_ident(KEY_WITH)
+                    || table_meta.path.is_ident(KEY_ATTRIBUTE_TYPE)
+                {
+                    table_meta.value()?.parse::<LitStr>()?;
+                    return Ok(());
+                }
+
+                if container.other && !table_meta.path.is_ident(KEY_OTHER) {
+                    return Err(table_meta.error("`other` field cannot be a key or an index"));
+                }
+
+                if table_meta.path.is_ident(KEY_OTHER) {
+                    if other {
+                        return Err(duplicate_attribute_error(&table_meta));
+                    }
+                    other = true;
+                } else if table_meta.path.is_ident(LOCAL_SECONDARY_INDEX_ENTRY) {
+                    parse_secondary_index_key_schemas(
+                        field,
+                        &table_meta,
+                        attribute_value_type,
+                        &mut container.attribute_definitions,
+                        &mut container.local_secondary_index_key_schemas,
+                        &mut container.local_secondary_index_projections,
+                        converted_with,
+                    )?;
+                } else if table_meta.path.is_ident(GLOBAL_SECONDARY_INDEX_ENTRY) {
+                    parse_secondary_index_key_schemas(
+                        field,
+                        &table_meta,
+                        attribute_value_type,
+                        &mut container.attribute_definitions,
+                        &mut container.global_secondary_index_key_schemas,
+                        &mut container.global_secondary_index_projections,
+                        converted_with,
+                    )?;
+                } else {
+                    parse_key_schemas(
+                        &[KeySchemaType::HashKey, KeySchemaType::RangeKey],
+                        field,
+                        &table_meta,
+                        attribute_value_type,
+                        &mut container.key_schemas,
+                        &mut container.attribute_definitions,
+                        converted_with,
+                    )?;
+                }
+
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_key_schemas(
+    key_types: &[KeySchemaType],
+    field: &Field,
+    table: &ParseNestedMeta,
+    attribute_value_type: AttributeValueType,
+    key_schemas: &mut Vec<KeySchema>,
+    attribute_definitions: &mut Vec<ScalarAttributeType>,
+    converted_with: bool,
+) -> Result<()> {
+    for key_type in key_types {
+        if table.path.is_ident(&key_type.to_string()) {
+            if is_float(&field.ty) {
+                return Err(table.error(
+                    "`f32` and `f64` fields cannot be keys, as key structs implement `Eq`",
+                ));
+            }
+            let scalar_attribute_type = ScalarAttributeType::from_attribute_value_type(
+                attribute_value_type,
+                field.ty.span(),
+            )
+            .map_err(|e| match converted_with {
+                true => table.error(format!(
+                    "a key converted `{KEY_WITH}` a module requires `{KEY_ATTRIBUTE_TYPE} = \"S\"`, `\"N\"` or `\"B\"`"
+                )),
+                false => e,
+            })?;
+            if let Some(ident) = &field.ident {
+                validate_key_attribute_name(&to_pascal_case(&ident.to_string()), ident.span())?;
+            }
+
+            push_key_schema(table, *key_type, key_schemas)?;
+
+            if !attribute_definitions.contains(&scalar_attribute_type) {
+                attribute_definitions.push(scalar_attribute_type);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// a field takes at most one key type per key schema
+fn push_key_schema(
+    meta: &ParseNestedMeta,
+    key_type: KeySchemaType,
+    key_schemas: &mut Vec<KeySchema>,
+) -> Result<()> {
+    match key_schemas.first().map(|ks| ks.key_type) {
+        Some(existing) if existing == key_type => Err(duplicate_attribute_error(meta)),
+        Some(existing) => Err(meta.error(format!("`{key_type}` conflicts with `{existing}`"))),
+        None => {
+            key_schemas.push(KeySchema {
+                key_type,
+                span: meta.path.span(),
+            });
+            Ok(())
+        }
+    }
+}
+
+fn parse_secondary_index_key_schemas(
+    field: &Field,
+    table: &ParseNestedMeta,
+    attribute_value_type: AttributeValueType,
+    attribute_definitions: &mut Vec<ScalarAttributeType>,
+    secondary_indexes: &mut BTreeMap<String, Vec<KeySchema>>,
+    projections: &mut BTreeSet<String>,
+    converted_with: bool,
+) -> Result<()> {
+    let mut index_name = None;
+    let mut key_schemas = vec![];
+    let mut project = false;
+
+    table.parse_nested_meta(|nested_meta| {
+        if !SECONDARY_INDEX_KEYS
+            .iter()
+            .any(|key| nested_meta.path.is_ident(key))
+        {
+            return Err(unknown_attribute_error(&nested_meta, SECONDARY_INDEX_KEYS));
+        }
+
+        if nested_meta.path.is_ident(SECONDARY_INDEX_NAME) {
+            if index_name.is_some() {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            let lit = nested_meta.value()?.parse::<Literal>()?;
+            let name = strip_quote_mark(&lit.to_string())
+                .ok_or(nested_meta.error("invalid index name"))?
+                .to_string();
+            if name.is_empty() {
+                return Err(nested_meta.error("empty index name"));
+            }
+            validate_index_name(&name, lit.span())?;
+            index_name = Some(name);
+        } else if nested_meta.path.is_ident(SECONDARY_INDEX_PROJECT) {
+            if project {
+                return Err(duplicate_attribute_error(&nested_meta));
+            }
+            project = true;
+        } else {
+            parse_key_schemas(
+                &[KeySchemaType::HashKey, KeySchemaType::RangeKey],
+                field,
+                &nested_meta,
+                attribute_value_type,
+                &mut key_schemas,
+                attribute_definitions,
+                converted_with,
+            )?;
+        }
+        Ok(())
+    })?;
+
+    let index_name = index_name.ok_or(table.error("empty index name"))?;
+    if project {
+        if let Some(key_schema) = key_schemas.first() {
+            return Err(table.error(format!(
+                "`{SECONDARY_INDEX_PROJECT}` conflicts with `{}`, key attributes are always projected",
+                key_schema.key_type
+            )));
+        }
+        if !projections.insert(index_name.clone()) {
+            return Err(table.error(format!(
+                "duplicate `{SECONDARY_INDEX_PROJECT}` for index `{index_name}`"
+            )));
+        }
+        return Ok(());
+    }
+    let key_schema = key_schemas.first().ok_or(table.error(format!(
+        "expected `{HASH_KEY}`, `{RANGE_KEY}` or `{SECONDARY_INDEX_PROJECT}`"
+    )))?;
+    let key_type = key_schema.key_type;
+
+    let index_key_schemas = secondary_indexes.entry(index_name.clone()).or_default();
+    match index_key_schemas.first().map(|ks| ks.key_type) {
+        Some(existing) if existing == key_type => {
+            Err(table.error(format!("duplicate `{key_type}` for index `{index_name}`")))
+        }
+        Some(existing) => Err(table.error(format!(
+            "`{key_type}` conflicts with `{existing}` for index `{index_name}`"
+        ))),
+        None => {
+            index_key_schemas.push(*key_schema);
+            Ok(())
+        }
+    }
+}
+
+/// floats do not implement `Eq`, which the key structs derive
+fn is_float(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("f32") || path.path.is_ident("f64"))
+}
+
+#[cfg(test)]
+mod test_parser {
+    use crate::container::Container;
+    use crate::dynamo::attribute_value::AttributeValueType;
+    use crate::table::parser::parse_from_dynamo_attrs;
+
+    use quote::quote;
+    use syn::{parse_quote, Field};
+
+    fn parse_field(field: Field) -> syn::Result<()> {
+        let target = quote! { self };
+        let mut container = Container::new(field.ident.as_ref().unwrap(), &field.ty, &target);
+        parse_from_dynamo_attrs(&field.attrs, &field, AttributeValueType::S, &mut container)
+    }
+
+    #[test]
+    fn unknown_field_attribute() {
+        let field = parse_quote! {
+            #[aws_dynamo(hash_kye)]
+            name: String
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "unknown aws_dynamo attribute `hash_kye`, did you mean `hash_key`?"
+        );
+
+        let field = parse_quote! {
+            #[aws_dynamo(global_secondary_index(index_nme = "gsi1", hash_key))]
+            name: String
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "unknown aws_dynamo attribute `index_nme`, did you mean `index_name`?"
+        );
+    }
+
+    #[test]
+    fn conflicting_field_attribute() {
+        let field = parse_quote! {
+            #[aws_dynamo(hash_key, range_key)]
+            name: String
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "`range_key` conflicts with `hash_key`"
+        );
+
+        let field = parse_quote! {
+            #[aws_dynamo(hash_key)]
+            #[aws_dynamo(hash_key)]
+            name: String
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "duplicate aws_dynamo attribute `hash_key`"
+        );
+
+        let field = parse_quote! {
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+            name: String
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "`range_key` conflicts with `hash_key` for index `lsi1`"
+        );
+    }
+
+    #[test]
+    fn float_key_field_attribute() {
+        let field = parse_quote! {
+            #[aws_dynamo(global_secondary_index(index_name = "gsi1", range_key))]
+            score: f64
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "`f32` and `f64` fields cannot be keys, as key structs implement `Eq`"
+        );
+    }
+
+    #[test]
+    fn index_name_in_any_order() -> syn::Result<()> {
+        let field = parse_quote! {
+            #[aws_dynamo(global_secondary_index(hash_key, index_name = "gsi1"))]
+            name: String
+        };
+        parse_field(field)
+    }
+
+    #[test]
+    fn project_field_attribute() -> syn::Result<()> {
+        let field: Field = parse_quote! {
+            #[aws_dynamo(global_secondary_index(index_name = "gsi1", project))]
+            #[aws_dynamo(local_secondary_index(index_name = "lsi1", project))]
+            name: Vec<bool>
+        };
+        let target = quote! { self };
+        let mut container = Container::new(field.ident.as_ref().unwrap(), &field.ty, &target);
+        parse_from_dynamo_attrs(&field.attrs, &field, AttributeValueType::L, &mut container)?;
+        assert!(container
+            .global_secondary_index_projections
+            .contains("gsi1"));
+        assert!(container.local_secondary_index_projections.contains("lsi1"));
+        assert!(container.global_secondary_index_key_schemas.is_empty());
+
+        let field = parse_quote! {
+            #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key, project))]
+            name: String
+        };
+        assert_eq!(
+            parse_field(field).unwrap_err().to_string(),
+            "`project` conflicts with `hash_key`, key attributes are always projected"
+        );
+        Ok(())
+    }
+}
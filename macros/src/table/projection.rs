@@ -0,0 +1,148 @@
+use crate::attrs::{ProjectionType, StructAttrs};
+use crate::bound::with_used_params;
+use crate::container;
+use crate::container::Container;
+use crate::table::index::SecondaryIndex;
+use crate::table::key::{expand_key_from_item, index_struct_name};
+use crate::table::tags::{INDEX_KEY_STRUCT_POSTFIX, INDEX_PROJECTION_STRUCT_POSTFIX};
+use crate::util::to_pascal_case;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{DataStruct, Error, GenericParam, Generics, Ident, LitStr, Result, Visibility};
+
+/// expands a struct holding the attributes projected into the index, as items read from an index
+/// projecting keys only or some attributes would not decode into the table struct.
+/// The struct converts like the table and into the key struct of the index.
+/// Indexes projecting all attributes alias the table struct instead.
+///
+/// No traits are derived, as fields of the table may not implement them.
+pub fn expand_projection_struct(
+    vis: &Visibility,
+    struct_name: &Ident,
+    generics: &Generics,
+    ds: &DataStruct,
+    index: &SecondaryIndex,
+    containers: &[Container],
+    struct_attrs: &StructAttrs,
+) -> Result<TokenStream> {
+    let projection_struct_name = index_struct_name(
+        struct_name,
+        &index.index_name,
+        INDEX_PROJECTION_STRUCT_POSTFIX,
+    );
+
+    if index.projection == ProjectionType::All {
+        let alias_generics = without_bounds(generics);
+        let (_, ty_generics, _) = generics.split_for_impl();
+        return Ok(quote! {
+            #vis type #projection_struct_name #alias_generics = #struct_name #ty_generics;
+        });
+    }
+
+    let projected_containers = containers
+        .iter()
+        .filter(|c| index.projects(c))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut fields = vec![];
+    for c in &projected_containers {
+        let field = ds
+            .fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(c.field_ident))
+            .ok_or(Error::new(c.field_ident.span(), "field not found"))?;
+        let (field_vis, ident, ty) = (&field.vis, c.field_ident, c.ty);
+        fields.push(quote! { #field_vis #ident: #ty });
+    }
+
+    let projection_generics = with_used_params(
+        generics,
+        &projected_containers
+            .iter()
+            .map(|c| c.ty)
+            .collect::<Vec<_>>(),
+    );
+    let where_clause = &projection_generics.where_clause;
+
+    // non-key attributes not bound to fields are expected in items read from the index
+    let mut projection_struct_attrs = struct_attrs.clone();
+    if let Some(allowed) = &mut projection_struct_attrs.deny_unknown_attributes {
+        allowed.extend(
+            index
+                .non_key_attributes
+                .iter()
+                .filter(|name| {
+                    !containers
+                        .iter()
+                        .any(|c| to_pascal_case(&c.field_ident.to_string()) == **name)
+                })
+                .map(|name| LitStr::new(name, Span::call_site())),
+        );
+    }
+    let conversions = container::expand_impl_conversions(
+        vis,
+        &projection_struct_name,
+        &projection_generics,
+        &projected_containers,
+        &projection_struct_attrs,
+    )?;
+
+    let key_struct_name =
+        index_struct_name(struct_name, &index.index_name, INDEX_KEY_STRUCT_POSTFIX);
+    let key_containers = projected_containers
+        .iter()
+        .filter(|c| {
+            !c.key_schemas.is_empty()
+                || c.local_secondary_index_key_schemas
+                    .contains_key(&index.index_name)
+                || c.global_secondary_index_key_schemas
+                    .contains_key(&index.index_name)
+        })
+        .collect::<Vec<_>>();
+    let into_key = expand_key_from_item(
+        &projection_struct_name,
+        &projection_generics,
+        &key_struct_name,
+        &key_containers,
+    );
+
+    Ok(quote! {
+        #vis struct #projection_struct_name #projection_generics #where_clause {
+            #( #fields, )*
+        }
+
+        #(
+            #[allow(clippy::needless_question_mark)]
+            #conversions
+        )*
+
+        #into_key
+    })
+}
+
+/// parameters of a type alias, whose bounds are not enforced
+fn without_bounds(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Lifetime(param) => {
+                param.colon_token = None;
+                param.bounds.clear();
+            }
+            GenericParam::Type(param) => {
+                param.colon_token = None;
+                param.bounds.clear();
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Const(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+        }
+    }
+    generics.where_clause = None;
+    generics
+}
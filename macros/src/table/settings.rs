@@ -0,0 +1,53 @@
+use crate::attrs::{Sse, TableClass};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+pub fn expand_table_class(table_class: TableClass) -> TokenStream {
+    let table_class = match table_class {
+        TableClass::Standard => quote! { Standard },
+        TableClass::StandardInfrequentAccess => quote! { StandardInfrequentAccess },
+    };
+    quote! { ::aws_sdk_dynamodb::types::TableClass::#table_class }
+}
+
+pub fn expand_sse_specification(sse: &Sse) -> TokenStream {
+    let kms_master_key_id = sse
+        .kms_master_key_id
+        .as_ref()
+        .map(|key_id| quote! { .kms_master_key_id(#key_id) });
+    quote! {
+        ::aws_sdk_dynamodb::types::SseSpecification::builder()
+            .enabled(true)
+            .sse_type(::aws_sdk_dynamodb::types::SseType::Kms)
+            #kms_master_key_id
+            .build()
+    }
+}
+
+/// point-in-time recovery is not part of `CreateTable`,
+/// so it is enabled through `UpdateContinuousBackups` once the table is active
+pub fn expand_enable_point_in_time_recovery_fn(table_name: &LitStr) -> TokenStream {
+    quote! {
+        async fn enable_point_in_time_recovery(
+            builder: ::aws_sdk_dynamodb::operation::update_continuous_backups::builders::UpdateContinuousBackupsFluentBuilder
+        ) -> ::std::result::Result<
+            ::aws_sdk_dynamodb::operation::update_continuous_backups::UpdateContinuousBackupsOutput,
+            ::aws_sdk_dynamodb::error::SdkError<
+                ::aws_sdk_dynamodb::operation::update_continuous_backups::UpdateContinuousBackupsError
+            >
+        > {
+            builder
+                .table_name(#table_name)
+                .point_in_time_recovery_specification(
+                    ::aws_sdk_dynamodb::types::PointInTimeRecoverySpecification::builder()
+                        .point_in_time_recovery_enabled(true)
+                        .build()
+                        .unwrap()
+                )
+                .send()
+                .await
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::attrs::StreamViewType;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn expand_stream_view_type(stream_view_type: StreamViewType) -> TokenStream {
+    let stream_view_type = match stream_view_type {
+        StreamViewType::NewAndOldImages => quote! { NewAndOldImages },
+        StreamViewType::NewImage => quote! { NewImage },
+        StreamViewType::OldImage => quote! { OldImage },
+        StreamViewType::KeysOnly => quote! { KeysOnly },
+    };
+    quote! { ::aws_sdk_dynamodb::types::StreamViewType::#stream_view_type }
+}
+
+pub fn expand_stream_specification(stream_view_type: StreamViewType) -> TokenStream {
+    let stream_view_type = expand_stream_view_type(stream_view_type);
+    quote! {
+        ::aws_sdk_dynamodb::types::StreamSpecification::builder()
+            .stream_enabled(true)
+            .stream_view_type(#stream_view_type)
+            .build()
+            .unwrap()
+    }
+}
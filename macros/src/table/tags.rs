@@ -0,0 +1,3 @@
+pub const PRIMARY_KEY_INPUT_STRUCT_POSTFIX: &str = "PrimaryKey";
+pub const INDEX_KEY_STRUCT_POSTFIX: &str = "Key";
+pub const INDEX_PROJECTION_STRUCT_POSTFIX: &str = "Projection";
@@ -0,0 +1,114 @@
+use crate::attrs::{BillingMode, OnDemandThroughput, ProvisionedThroughput, StructAttrs};
+use crate::tags::{KEY_BILLING_MODE, KEY_ON_DEMAND_THROUGHPUT, KEY_PROVISIONED_THROUGHPUT};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+
+/// resolves the billing mode of the table, inferred from the throughput of the table and its GSIs
+/// unless declared with `billing_mode`, and checks the throughput fits it:
+/// provisioned tables take provisioned throughput only, which the table itself must declare,
+/// and on-demand tables take on-demand throughput only.
+pub fn resolve_billing_mode(struct_attrs: &StructAttrs) -> Result<Option<BillingMode>> {
+    let provisioned = struct_attrs.provisioned_throughput.iter().chain(
+        struct_attrs
+            .global_secondary_indexes
+            .iter()
+            .filter_map(|index| index.provisioned_throughput.as_ref()),
+    );
+    let on_demand = struct_attrs.on_demand_throughput.iter().chain(
+        struct_attrs
+            .global_secondary_indexes
+            .iter()
+            .filter_map(|index| index.on_demand_throughput.as_ref()),
+    );
+
+    // the span of the declared billing mode, or of the throughput it is inferred from
+    let (billing_mode, span) = match (
+        struct_attrs.billing_mode,
+        provisioned.clone().next(),
+        on_demand.clone().next(),
+    ) {
+        (Some((billing_mode, span)), _, _) => (billing_mode, span),
+        (None, Some(throughput), _) => (BillingMode::Provisioned, throughput.span),
+        (None, None, Some(throughput)) => (BillingMode::PayPerRequest, throughput.span),
+        (None, None, None) => return Ok(None),
+    };
+    let conflict = |key: &str| match struct_attrs.billing_mode {
+        Some(_) => format!("`{key}` conflicts with {KEY_BILLING_MODE} `{billing_mode}`"),
+        None => format!("`{key}` conflicts with `{}`", conflicting_key(billing_mode)),
+    };
+
+    match billing_mode {
+        BillingMode::Provisioned => {
+            if let Some(throughput) = on_demand.clone().next() {
+                return Err(Error::new(
+                    throughput.span,
+                    conflict(KEY_ON_DEMAND_THROUGHPUT),
+                ));
+            }
+            if struct_attrs.provisioned_throughput.is_none() {
+                return Err(Error::new(
+                    span,
+                    format!("{KEY_BILLING_MODE} `{billing_mode}` requires `{KEY_PROVISIONED_THROUGHPUT}` on the table"),
+                ));
+            }
+        }
+        BillingMode::PayPerRequest => {
+            if let Some(throughput) = provisioned.clone().next() {
+                return Err(Error::new(
+                    throughput.span,
+                    conflict(KEY_PROVISIONED_THROUGHPUT),
+                ));
+            }
+        }
+    }
+
+    Ok(Some(billing_mode))
+}
+
+/// the throughput a billing mode is inferred from
+fn conflicting_key(billing_mode: BillingMode) -> &'static str {
+    match billing_mode {
+        BillingMode::Provisioned => KEY_PROVISIONED_THROUGHPUT,
+        BillingMode::PayPerRequest => KEY_ON_DEMAND_THROUGHPUT,
+    }
+}
+
+pub fn expand_billing_mode(billing_mode: BillingMode) -> TokenStream {
+    let billing_mode = match billing_mode {
+        BillingMode::Provisioned => quote! { Provisioned },
+        BillingMode::PayPerRequest => quote! { PayPerRequest },
+    };
+    quote! { ::aws_sdk_dynamodb::types::BillingMode::#billing_mode }
+}
+
+pub fn expand_provisioned_throughput(throughput: &ProvisionedThroughput) -> TokenStream {
+    let ProvisionedThroughput {
+        read_capacity_units,
+        write_capacity_units,
+        ..
+    } = throughput;
+    quote! {
+        ::aws_sdk_dynamodb::types::ProvisionedThroughput::builder()
+            .read_capacity_units(#read_capacity_units)
+            .write_capacity_units(#write_capacity_units)
+            .build()
+            .unwrap()
+    }
+}
+
+pub fn expand_on_demand_throughput(throughput: &OnDemandThroughput) -> TokenStream {
+    let max_read_request_units = throughput
+        .max_read_request_units
+        .map(|units| quote! { .max_read_request_units(#units) });
+    let max_write_request_units = throughput
+        .max_write_request_units
+        .map(|units| quote! { .max_write_request_units(#units) });
+    quote! {
+        ::aws_sdk_dynamodb::types::OnDemandThroughput::builder()
+            #max_read_request_units
+            #max_write_request_units
+            .build()
+    }
+}
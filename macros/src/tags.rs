@@ -0,0 +1,27 @@
+pub const AWS_DYNAMO_ATTR_META_ENTRY: &str = "aws_dynamo";
+pub const KEY_TABLE_NAME: &str = "table_name";
+pub const KEY_OTHER: &str = "other";
+pub const KEY_WITH: &str = "with";
+pub const KEY_ATTRIBUTE_TYPE: &str = "attribute_type";
+pub const KEY_DENY_UNKNOWN_ATTRIBUTES: &str = "deny_unknown_attributes";
+pub const KEY_ALLOW: &str = "allow";
+pub const KEY_LENIENT_NUMBERS: &str = "lenient_numbers";
+pub const KEY_EMPTY_SETS: &str = "empty_sets";
+pub const KEY_LOCAL_SECONDARY_INDEX: &str = "local_secondary_index";
+pub const KEY_GLOBAL_SECONDARY_INDEX: &str = "global_secondary_index";
+pub const KEY_INDEX_NAME: &str = "index_name";
+pub const KEY_PROJECTION: &str = "projection";
+pub const KEY_NON_KEY_ATTRIBUTES: &str = "non_key_attributes";
+pub const KEY_BILLING_MODE: &str = "billing_mode";
+pub const KEY_PROVISIONED_THROUGHPUT: &str = "provisioned_throughput";
+pub const KEY_READ_CAPACITY_UNITS: &str = "read_capacity_units";
+pub const KEY_WRITE_CAPACITY_UNITS: &str = "write_capacity_units";
+pub const KEY_ON_DEMAND_THROUGHPUT: &str = "on_demand_throughput";
+pub const KEY_MAX_READ_REQUEST_UNITS: &str = "max_read_request_units";
+pub const KEY_MAX_WRITE_REQUEST_UNITS: &str = "max_write_request_units";
+pub const KEY_STREAM: &str = "stream";
+pub const KEY_TABLE_CLASS: &str = "table_class";
+pub const KEY_DELETION_PROTECTION: &str = "deletion_protection";
+pub const KEY_SSE: &str = "sse";
+pub const KEY_KMS_MASTER_KEY_ID: &str = "kms_master_key_id";
+pub const KEY_POINT_IN_TIME_RECOVERY: &str = "point_in_time_recovery";
@@ -0,0 +1,57 @@
+pub fn to_pascal_case(val: &str) -> String {
+    let mut pascal = String::new();
+    let mut capitalize = true;
+    for ch in val.chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            pascal.push(ch.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            pascal.push(ch);
+        }
+    }
+    pascal
+}
+
+pub fn strip_quote_mark(val: &str) -> Option<&str> {
+    val.strip_prefix('"')?.strip_suffix('"')
+}
+
+/// returns the closest candidate to `val` within a small edit distance
+pub fn did_you_mean<'a>(val: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(val, candidate), *candidate))
+        .filter(|(distance, candidate)| *distance <= 3 && *distance < candidate.len())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr.push(substitution.min(prev[j + 1] + 1).min(curr[j] + 1));
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod test_util {
+    use crate::util::did_you_mean;
+
+    #[test]
+    fn suggest_closest_candidate() {
+        let candidates = ["hash_key", "range_key", "table_name"];
+        assert_eq!(did_you_mean("hash_kye", &candidates), Some("hash_key"));
+        assert_eq!(did_you_mean("tabel_name", &candidates), Some("table_name"));
+        assert_eq!(did_you_mean("rangekey", &candidates), Some("range_key"));
+        assert_eq!(did_you_mean("projection", &candidates), None);
+    }
+}
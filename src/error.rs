@@ -0,0 +1,385 @@
+use aws_sdk_dynamodb::types::AttributeValue;
+use std::fmt::{Display, Formatter};
+
+/// Data type of an `AttributeValue`, named after DynamoDB data type descriptors.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum AttributeValueType {
+    B,
+    Bool,
+    Bs,
+    L,
+    M,
+    N,
+    Ns,
+    Null,
+    S,
+    Ss,
+    Unknown,
+}
+
+impl AttributeValueType {
+    pub fn of(value: &AttributeValue) -> Self {
+        match value {
+            AttributeValue::B(_) => Self::B,
+            AttributeValue::Bool(_) => Self::Bool,
+            AttributeValue::Bs(_) => Self::Bs,
+            AttributeValue::L(_) => Self::L,
+            AttributeValue::M(_) => Self::M,
+            AttributeValue::N(_) => Self::N,
+            AttributeValue::Ns(_) => Self::Ns,
+            AttributeValue::Null(_) => Self::Null,
+            AttributeValue::S(_) => Self::S,
+            AttributeValue::Ss(_) => Self::Ss,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for AttributeValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            Self::B => "B",
+            Self::Bool => "BOOL",
+            Self::Bs => "BS",
+            Self::L => "L",
+            Self::M => "M",
+            Self::N => "N",
+            Self::Ns => "NS",
+            Self::Null => "NULL",
+            Self::S => "S",
+            Self::Ss => "SS",
+            Self::Unknown => "UNKNOWN",
+        };
+        write!(f, "{val}")
+    }
+}
+
+/// A step into a nested attribute: a map key or a list index.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AttributePathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// What went wrong while converting an `AttributeValue` into a Rust type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum DynamoConversionErrorKind {
+    /// the attribute is not present
+    Missing,
+    /// the attribute holds another data type than the field expects
+    TypeMismatch,
+    /// the attribute holds the expected data type but its content cannot be parsed
+    ParseFailure,
+    /// the item holds attributes that no field names, under `deny_unknown_attributes`
+    UnknownAttributes,
+    /// the number exceeds the precision or magnitude DynamoDB supports
+    OutOfRange,
+    /// the set is empty, which DynamoDB rejects
+    EmptySet,
+    /// the key attribute is an empty string or binary, which DynamoDB rejects
+    EmptyKey,
+}
+
+/// Error returned by `from_attribute_value`, `try_to_attribute_value` and the generated `TryFrom` impls.
+///
+/// Carries the path to the offending attribute such as `Inner[3].Value`,
+/// the expected and actual data types, and the raw value if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamoConversionError {
+    inner: Box<ErrorImpl>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ErrorImpl {
+    kind: DynamoConversionErrorKind,
+    path: Vec<AttributePathSegment>,
+    expected: Option<AttributeValueType>,
+    value: Option<AttributeValue>,
+    message: Option<String>,
+    unknown_attributes: Vec<String>,
+}
+
+impl DynamoConversionError {
+    fn new(inner: ErrorImpl) -> Self {
+        Self {
+            inner: Box::new(inner),
+        }
+    }
+
+    pub fn missing() -> Self {
+        Self::new(ErrorImpl::new(DynamoConversionErrorKind::Missing))
+    }
+
+    pub fn type_mismatch(expected: AttributeValueType, value: &AttributeValue) -> Self {
+        Self::new(ErrorImpl {
+            expected: Some(expected),
+            value: Some(value.clone()),
+            ..ErrorImpl::new(DynamoConversionErrorKind::TypeMismatch)
+        })
+    }
+
+    pub fn parse_failure(
+        expected: AttributeValueType,
+        value: &AttributeValue,
+        message: impl Display,
+    ) -> Self {
+        Self::new(ErrorImpl {
+            expected: Some(expected),
+            value: Some(value.clone()),
+            message: Some(message.to_string()),
+            ..ErrorImpl::new(DynamoConversionErrorKind::ParseFailure)
+        })
+    }
+
+    pub fn out_of_range(
+        expected: AttributeValueType,
+        value: &AttributeValue,
+        message: impl Display,
+    ) -> Self {
+        Self::new(ErrorImpl {
+            expected: Some(expected),
+            value: Some(value.clone()),
+            message: Some(message.to_string()),
+            ..ErrorImpl::new(DynamoConversionErrorKind::OutOfRange)
+        })
+    }
+
+    pub fn empty_set(value: &AttributeValue) -> Self {
+        Self::new(ErrorImpl {
+            value: Some(value.clone()),
+            ..ErrorImpl::new(DynamoConversionErrorKind::EmptySet)
+        })
+    }
+
+    pub fn empty_key(value: &AttributeValue) -> Self {
+        Self::new(ErrorImpl {
+            value: Some(value.clone()),
+            ..ErrorImpl::new(DynamoConversionErrorKind::EmptyKey)
+        })
+    }
+
+    pub fn unknown_attributes(mut names: Vec<String>) -> Self {
+        names.sort();
+        Self::new(ErrorImpl {
+            unknown_attributes: names,
+            ..ErrorImpl::new(DynamoConversionErrorKind::UnknownAttributes)
+        })
+    }
+
+    /// prepends a segment to the path, as the error propagates to the outer attribute
+    pub fn prepend_path(mut self, segment: AttributePathSegment) -> Self {
+        self.inner.path.insert(0, segment);
+        self
+    }
+
+    pub fn kind(&self) -> DynamoConversionErrorKind {
+        self.inner.kind
+    }
+
+    pub fn path(&self) -> &[AttributePathSegment] {
+        &self.inner.path
+    }
+
+    /// path formatted as `Inner[3].Value`
+    pub fn path_string(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.inner.path {
+            match segment {
+                AttributePathSegment::Key(key) if path.is_empty() => path.push_str(key),
+                AttributePathSegment::Key(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                AttributePathSegment::Index(index) => path.push_str(&format!("[{index}]")),
+            }
+        }
+        path
+    }
+
+    pub fn expected(&self) -> Option<AttributeValueType> {
+        self.inner.expected
+    }
+
+    pub fn actual(&self) -> Option<AttributeValueType> {
+        self.inner.value.as_ref().map(AttributeValueType::of)
+    }
+
+    /// raw value of the offending attribute
+    pub fn value(&self) -> Option<&AttributeValue> {
+        self.inner.value.as_ref()
+    }
+
+    /// names of unexpected attributes, sorted
+    pub fn unknown_attribute_names(&self) -> &[String] {
+        &self.inner.unknown_attributes
+    }
+}
+
+impl ErrorImpl {
+    fn new(kind: DynamoConversionErrorKind) -> Self {
+        Self {
+            kind,
+            path: vec![],
+            expected: None,
+            value: None,
+            message: None,
+            unknown_attributes: vec![],
+        }
+    }
+}
+
+impl Display for DynamoConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let at = if self.inner.path.is_empty() {
+            String::new()
+        } else {
+            format!(" at `{}`", self.path_string())
+        };
+
+        match self.inner.kind {
+            DynamoConversionErrorKind::Missing => write!(f, "missing attribute{at}"),
+            DynamoConversionErrorKind::TypeMismatch => {
+                write!(f, "type mismatch{at}: expected ")?;
+                write_type(f, self.expected())?;
+                write!(f, ", found ")?;
+                write_type(f, self.actual())
+            }
+            DynamoConversionErrorKind::ParseFailure => {
+                write!(f, "failed to parse ")?;
+                write_type(f, self.expected())?;
+                write!(f, "{at}")?;
+                if let Some(message) = &self.inner.message {
+                    write!(f, ": {message}")?;
+                }
+                if let Some(value) = self.value() {
+                    write!(f, " (value: {value:?})")?;
+                }
+                Ok(())
+            }
+            DynamoConversionErrorKind::OutOfRange => {
+                write!(f, "number out of range{at}")?;
+                if let Some(message) = &self.inner.message {
+                    write!(f, ": {message}")?;
+                }
+                Ok(())
+            }
+            DynamoConversionErrorKind::EmptySet => {
+                write!(f, "empty ")?;
+                write_type(f, self.actual())?;
+                write!(f, "{at}")
+            }
+            DynamoConversionErrorKind::EmptyKey => write!(f, "empty key attribute{at}"),
+            DynamoConversionErrorKind::UnknownAttributes => write!(
+                f,
+                "unknown attributes{at}: {}",
+                self.unknown_attribute_names()
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+fn write_type(f: &mut Formatter<'_>, ty: Option<AttributeValueType>) -> std::fmt::Result {
+    match ty {
+        Some(ty) => write!(f, "{ty}"),
+        None => write!(f, "unknown type"),
+    }
+}
+
+impl std::error::Error for DynamoConversionError {}
+
+/// Every failure found while converting an item, returned by `try_from_attribute_value_all_errors`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamoConversionErrors {
+    errors: Vec<DynamoConversionError>,
+}
+
+impl DynamoConversionErrors {
+    pub fn errors(&self) -> &[DynamoConversionError] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<DynamoConversionError> {
+        self.errors
+    }
+
+    pub(crate) fn prepend_path(mut self, segment: AttributePathSegment) -> Self {
+        self.errors = self
+            .errors
+            .into_iter()
+            .map(|e| e.prepend_path(segment.clone()))
+            .collect();
+        self
+    }
+
+    pub(crate) fn merge(mut self, other: Self) -> Self {
+        self.errors.extend(other.errors);
+        self
+    }
+}
+
+impl From<DynamoConversionError> for DynamoConversionErrors {
+    fn from(value: DynamoConversionError) -> Self {
+        Self {
+            errors: vec![value],
+        }
+    }
+}
+
+impl IntoIterator for DynamoConversionErrors {
+    type Item = DynamoConversionError;
+    type IntoIter = std::vec::IntoIter<DynamoConversionError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl Display for DynamoConversionErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} conversion error(s)", self.errors.len())?;
+        for error in &self.errors {
+            write!(f, "\n- {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DynamoConversionErrors {}
+
+#[cfg(test)]
+mod test_error {
+    use crate::error::{AttributePathSegment, AttributeValueType, DynamoConversionError};
+
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    #[test]
+    fn display_with_path() {
+        let err = DynamoConversionError::type_mismatch(
+            AttributeValueType::N,
+            &AttributeValue::S("abc".to_string()),
+        )
+        .prepend_path(AttributePathSegment::Key("Value".to_string()))
+        .prepend_path(AttributePathSegment::Index(3))
+        .prepend_path(AttributePathSegment::Key("Inner".to_string()));
+
+        assert_eq!(err.path_string(), "Inner[3].Value");
+        assert_eq!(err.actual(), Some(AttributeValueType::S));
+        assert_eq!(
+            err.to_string(),
+            "type mismatch at `Inner[3].Value`: expected N, found S"
+        );
+
+        let err = DynamoConversionError::missing()
+            .prepend_path(AttributePathSegment::Key("Name".to_string()));
+        assert_eq!(err.to_string(), "missing attribute at `Name`");
+
+        let err = DynamoConversionError::unknown_attributes(vec!["B".to_string(), "A".to_string()]);
+        assert_eq!(err.to_string(), "unknown attributes: `A`, `B`");
+    }
+}
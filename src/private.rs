@@ -0,0 +1,1141 @@
+//! Helpers called by the generated code. Not a public API.
+
+use crate::error::{
+    AttributePathSegment, AttributeValueType, DynamoConversionError, DynamoConversionErrors,
+};
+
+use aws_sdk_dynamodb::operation::put_item::builders::PutItemFluentBuilder;
+use aws_sdk_dynamodb::primitives::Blob;
+use aws_sdk_dynamodb::types::AttributeValue;
+use std::collections::HashMap;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+type Result<T, E = DynamoConversionError> = std::result::Result<T, E>;
+
+/// Error type the generated decoding is generic over.
+/// Fails fast on the first error unless `ACCUMULATE` is set.
+pub trait DecodeError: From<DynamoConversionError> + Sized {
+    const ACCUMULATE: bool;
+
+    fn prepend_path(self, segment: AttributePathSegment) -> Self;
+
+    fn merge(self, other: Self) -> Self;
+}
+
+impl DecodeError for DynamoConversionError {
+    const ACCUMULATE: bool = false;
+
+    fn prepend_path(self, segment: AttributePathSegment) -> Self {
+        self.prepend_path(segment)
+    }
+
+    fn merge(self, _: Self) -> Self {
+        self
+    }
+}
+
+impl DecodeError for DynamoConversionErrors {
+    const ACCUMULATE: bool = true;
+
+    fn prepend_path(self, segment: AttributePathSegment) -> Self {
+        self.prepend_path(segment)
+    }
+
+    fn merge(self, other: Self) -> Self {
+        self.merge(other)
+    }
+}
+
+/// Implemented by structs deriving `Item` or `Table`.
+pub trait ToAttributeMap {
+    fn to_attribute_map(&self) -> HashMap<String, AttributeValue>;
+
+    /// moves the fields into the attributes instead of copying them
+    fn into_attribute_map(self) -> HashMap<String, AttributeValue>
+    where
+        Self: Sized;
+}
+
+impl<T: ToAttributeMap + ?Sized> ToAttributeMap for &T {
+    fn to_attribute_map(&self) -> HashMap<String, AttributeValue> {
+        (**self).to_attribute_map()
+    }
+
+    fn into_attribute_map(self) -> HashMap<String, AttributeValue> {
+        (*self).to_attribute_map()
+    }
+}
+
+/// Implemented by structs deriving `Item` or `Table`.
+/// `&str` and `&[u8]` fields borrow from the map for `'de`.
+pub trait FromAttributeMap<'de>: Sized {
+    fn from_attribute_map<E: DecodeError>(
+        value: &'de HashMap<String, AttributeValue>,
+    ) -> Result<Self, E>;
+}
+
+/// Implemented by structs deriving `Item` or `Table` without lifetime parameters.
+pub trait FromOwnedAttributeMap: Sized {
+    /// takes the attributes out of the map instead of copying them
+    fn from_owned_attribute_map<E: DecodeError>(
+        value: HashMap<String, AttributeValue>,
+    ) -> Result<Self, E>;
+}
+
+/// adds the attributes of an item to the builder one by one,
+/// keeping the attributes already put on it unless the item overwrites them
+pub fn put_item_attributes(
+    builder: PutItemFluentBuilder,
+    item: HashMap<String, AttributeValue>,
+) -> PutItemFluentBuilder {
+    item.into_iter()
+        .fold(builder, |builder, (key, value)| builder.item(key, value))
+}
+
+/// records `e`, returning it right away unless `E` accumulates
+pub fn record<E: DecodeError>(error: &mut Option<E>, e: E) -> Result<(), E> {
+    if !E::ACCUMULATE {
+        return Err(e);
+    }
+    merge_into(error, e);
+    Ok(())
+}
+
+fn merge_into<E: DecodeError>(error: &mut Option<E>, e: E) {
+    *error = Some(match error.take() {
+        Some(prev) => prev.merge(e),
+        None => e,
+    });
+}
+
+pub fn record_field<T, E: DecodeError>(
+    error: &mut Option<E>,
+    result: Result<T, E>,
+) -> Result<Option<T>, E> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(e) => record(error, e).map(|_| None),
+    }
+}
+
+pub fn finish<E: DecodeError>(error: Option<E>) -> Result<(), E> {
+    match error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// collects decoded elements, recording every failure when `E` accumulates
+pub fn collect<T, C, E>(iter: impl IntoIterator<Item = Result<T, E>>) -> Result<C, E>
+where
+    C: FromIterator<T>,
+    E: DecodeError,
+{
+    let mut error = None;
+    let collection = iter
+        .into_iter()
+        .map_while(|result| match result {
+            Ok(value) => Some(Some(value)),
+            Err(e) => {
+                merge_into(&mut error, e);
+                E::ACCUMULATE.then_some(None)
+            }
+        })
+        .flatten()
+        .collect();
+    finish(error)?;
+    Ok(collection)
+}
+
+/// runs `f`, prepending `key` to the path of the error
+pub fn with_key<T, E: DecodeError>(key: &str, f: impl FnOnce() -> Result<T, E>) -> Result<T, E> {
+    f().map_err(|e| e.prepend_path(AttributePathSegment::Key(key.to_string())))
+}
+
+/// runs `f`, prepending `index` to the path of the error
+pub fn with_index<T, E: DecodeError>(
+    index: usize,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    f().map_err(|e| e.prepend_path(AttributePathSegment::Index(index)))
+}
+
+/// Integer types a number attribute decodes into.
+pub trait FromN: Sized {
+    fn from_n(n: &str) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_from_n {
+    ($( $ty:ty ),*) => {
+        $(
+            impl FromN for $ty {
+                fn from_n(n: &str) -> Result<Self, ParseIntError> {
+                    n.parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_n!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+
+/// parses a number attribute into an integer,
+/// accepting any form DynamoDB may normalize an exact integer into, such as `1E+2` or `100.0`
+pub fn parse_n<T: FromN>(n: &str) -> Result<T> {
+    let error = |message: String| {
+        DynamoConversionError::parse_failure(
+            AttributeValueType::N,
+            &AttributeValue::N(n.to_string()),
+            message,
+        )
+    };
+
+    let normalized =
+        normalize_integer(n).ok_or_else(|| error(format!("`{n}` is not an integer")))?;
+    let out_of_range = || {
+        error(format!(
+            "`{n}` is out of range for `{}`",
+            std::any::type_name::<T>()
+        ))
+    };
+    T::from_n(&normalized).map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => out_of_range(),
+        // unsigned types reject the sign of a negative integer as an invalid digit
+        IntErrorKind::InvalidDigit if normalized.starts_with('-') => out_of_range(),
+        _ => error(e.to_string()),
+    })
+}
+
+/// parses a number attribute into a floating-point number, rejecting numbers beyond its magnitude.
+/// `parse` alone would also accept `inf` and `NaN`, which DynamoDB never stores.
+pub fn parse_float<T: FromStr + Into<f64> + Copy>(n: &str) -> Result<T> {
+    let error = |message: String| {
+        DynamoConversionError::parse_failure(
+            AttributeValueType::N,
+            &AttributeValue::N(n.to_string()),
+            message,
+        )
+    };
+
+    if parse_decimal(n).is_none() {
+        return Err(error(format!("`{n}` is not a number")));
+    }
+    let value = n
+        .parse::<T>()
+        .map_err(|_| error(format!("`{n}` is not a number")))?;
+    if !value.into().is_finite() {
+        return Err(error(format!(
+            "`{n}` is out of range for `{}`",
+            std::any::type_name::<T>()
+        )));
+    }
+    Ok(value)
+}
+
+/// digits beyond any 128-bit integer, so that larger exponents overflow without allocating them
+const MAX_INTEGER_DIGITS: usize = 40;
+
+/// numeric string split into its significant digits and the position of the decimal point within them
+struct Decimal {
+    negative: bool,
+    /// empty for zero
+    digits: String,
+    point: i64,
+}
+
+fn parse_decimal(n: &str) -> Option<Decimal> {
+    let (negative, unsigned) = match n.as_bytes().first()? {
+        b'-' => (true, &n[1..]),
+        b'+' => (false, &n[1..]),
+        _ => (false, n),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(i) => (&unsigned[..i], unsigned[i + 1..].parse::<i64>().ok()?),
+        None => (unsigned, 0),
+    };
+    let (integer_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer_part.is_empty() && fraction_part.is_empty()
+        || !integer_part
+            .bytes()
+            .chain(fraction_part.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let all_digits = format!("{integer_part}{fraction_part}");
+    let digits = all_digits.trim_start_matches('0');
+    let leading_zeros = (all_digits.len() - digits.len()) as i64;
+    let point = (integer_part.len() as i64).checked_add(exponent)? - leading_zeros;
+
+    Some(Decimal {
+        negative,
+        digits: digits.trim_end_matches('0').to_string(),
+        point,
+    })
+}
+
+/// rewrites a numeric string into plain integer digits, or `None` if it is not an exact integer
+fn normalize_integer(n: &str) -> Option<String> {
+    let Decimal {
+        negative,
+        digits,
+        point,
+    } = parse_decimal(n)?;
+    if digits.is_empty() {
+        return Some("0".to_string());
+    }
+    if point < digits.len() as i64 {
+        return None;
+    }
+
+    let zeros = (point as usize - digits.len()).min(MAX_INTEGER_DIGITS);
+    let sign = if negative { "-" } else { "" };
+    Some(format!("{sign}{digits}{}", "0".repeat(zeros)))
+}
+
+/// significant digits DynamoDB keeps for a number
+const MAX_N_PRECISION: usize = 38;
+/// exponents of the most significant digit DynamoDB accepts, as in `1E-130` and `9.9E+125`
+const N_EXPONENT_RANGE: std::ops::RangeInclusive<i64> = -130..=125;
+
+/// checks that DynamoDB accepts `n` without rounding or rejecting it
+fn check_n(n: &str) -> std::result::Result<(), String> {
+    let Decimal { digits, point, .. } =
+        parse_decimal(n).ok_or_else(|| format!("`{n}` is not a number"))?;
+    if digits.is_empty() {
+        return Ok(());
+    }
+
+    let precision = digits.len();
+    if precision > MAX_N_PRECISION {
+        return Err(format!(
+            "`{n}` has {precision} significant digits, more than the {MAX_N_PRECISION} DynamoDB supports"
+        ));
+    }
+    if !N_EXPONENT_RANGE.contains(&(point - 1)) {
+        return Err(format!("`{n}` is beyond the magnitude DynamoDB supports"));
+    }
+    Ok(())
+}
+
+/// checks every number and set in the item, returning the first one DynamoDB would reject
+pub fn validate_attributes(map: &HashMap<String, AttributeValue>) -> Result<()> {
+    map.iter()
+        .try_for_each(|(key, value)| with_key(key, || validate_attribute(value)))
+}
+
+fn validate_attribute(value: &AttributeValue) -> Result<()> {
+    if is_empty_set(value) {
+        return Err(DynamoConversionError::empty_set(value));
+    }
+
+    let validate_n = |n: &String| {
+        check_n(n).map_err(|message| {
+            DynamoConversionError::out_of_range(
+                AttributeValueType::N,
+                &AttributeValue::N(n.to_string()),
+                message,
+            )
+        })
+    };
+
+    match value {
+        AttributeValue::N(n) => validate_n(n),
+        AttributeValue::Ns(ns) => ns
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, n)| with_index(index, || validate_n(n))),
+        AttributeValue::L(l) => l
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, v)| with_index(index, || validate_attribute(v))),
+        AttributeValue::M(m) => validate_attributes(m),
+        _ => Ok(()),
+    }
+}
+
+/// checks that no key attribute is an empty string or binary
+pub fn validate_keys(map: &HashMap<String, AttributeValue>, keys: &[&str]) -> Result<()> {
+    keys.iter().try_for_each(|key| match map.get(*key) {
+        Some(value @ AttributeValue::S(s)) if s.is_empty() => Err(empty_key(key, value)),
+        Some(value @ AttributeValue::B(b)) if b.as_ref().is_empty() => Err(empty_key(key, value)),
+        _ => Ok(()),
+    })
+}
+
+fn empty_key(key: &str, value: &AttributeValue) -> DynamoConversionError {
+    DynamoConversionError::empty_key(value).prepend_path(AttributePathSegment::Key(key.to_string()))
+}
+
+fn is_empty_set(value: &AttributeValue) -> bool {
+    match value {
+        AttributeValue::Bs(bs) => bs.is_empty(),
+        AttributeValue::Ns(ns) => ns.is_empty(),
+        AttributeValue::Ss(ss) => ss.is_empty(),
+        _ => false,
+    }
+}
+
+/// inserts `value` unless it is an empty set, under `empty_sets = "omit"`
+fn insert_omitting_empty_set(
+    map: &mut HashMap<String, AttributeValue>,
+    key: String,
+    value: AttributeValue,
+) {
+    if !is_empty_set(&value) {
+        map.insert(key, value);
+    }
+}
+
+/// replaces an empty set with an empty L
+fn empty_set_as_list(value: AttributeValue) -> AttributeValue {
+    if is_empty_set(&value) {
+        AttributeValue::L(vec![])
+    } else {
+        value
+    }
+}
+
+/// reads a set attribute, decoding an empty L as an empty set
+fn as_set<'a, T>(
+    value: &'a AttributeValue,
+    as_set: impl FnOnce(&'a AttributeValue) -> Result<&'a Vec<T>>,
+) -> Result<&'a [T]> {
+    match value {
+        AttributeValue::L(l) if l.is_empty() => Ok(&[]),
+        value => as_set(value).map(Vec::as_slice),
+    }
+}
+
+/// takes a set attribute, decoding an empty L as an empty set
+fn into_set<T>(
+    value: AttributeValue,
+    into_set: impl FnOnce(AttributeValue) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    match value {
+        AttributeValue::L(l) if l.is_empty() => Ok(vec![]),
+        value => into_set(value),
+    }
+}
+
+macro_rules! expand_as_fns {
+    ($( $fn_name:ident => $ty:ty, $attribute_value_type:ident; )*) => {
+        $(
+            pub fn $fn_name(value: &AttributeValue) -> Result<&$ty> {
+                value.$fn_name().map_err(|value| {
+                    DynamoConversionError::type_mismatch(AttributeValueType::$attribute_value_type, value)
+                })
+            }
+        )*
+    };
+}
+
+expand_as_fns! {
+    as_b => Blob, B;
+    as_bool => bool, Bool;
+    as_bs => Vec<Blob>, Bs;
+    as_l => Vec<AttributeValue>, L;
+    as_m => HashMap<String, AttributeValue>, M;
+    as_n => String, N;
+    as_ns => Vec<String>, Ns;
+    as_null => bool, Null;
+    as_s => String, S;
+    as_ss => Vec<String>, Ss;
+}
+
+macro_rules! expand_into_fns {
+    ($( $fn_name:ident => $ty:ty, $variant:ident; )*) => {
+        $(
+            pub fn $fn_name(value: AttributeValue) -> Result<$ty> {
+                match value {
+                    AttributeValue::$variant(value) => Ok(value),
+                    value => Err(DynamoConversionError::type_mismatch(
+                        AttributeValueType::$variant,
+                        &value,
+                    )),
+                }
+            }
+        )*
+    };
+}
+
+expand_into_fns! {
+    into_b => Blob, B;
+    into_bool => bool, Bool;
+    into_bs => Vec<Blob>, Bs;
+    into_l => Vec<AttributeValue>, L;
+    into_m => HashMap<String, AttributeValue>, M;
+    into_null => bool, Null;
+    into_s => String, S;
+    into_ss => Vec<String>, Ss;
+}
+
+/// reads a number attribute, also accepting numbers stored as S under `lenient_numbers`
+fn as_n_lenient(value: &AttributeValue) -> Result<&String> {
+    match value {
+        AttributeValue::S(s) => Ok(s),
+        _ => as_n(value),
+    }
+}
+
+/// reads a number set attribute, also accepting numbers stored as SS under `lenient_numbers`
+fn as_ns_lenient(value: &AttributeValue) -> Result<&Vec<String>> {
+    match value {
+        AttributeValue::Ss(ss) => Ok(ss),
+        _ => as_ns(value),
+    }
+}
+
+/// How empty sets are written, following `#[aws_dynamo(empty_sets = "..")]`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EmptySets {
+    #[default]
+    Omit,
+    List,
+    Error,
+}
+
+/// Options of the struct a field is converted for.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Options {
+    pub lenient_numbers: bool,
+    pub empty_sets: EmptySets,
+}
+
+impl Options {
+    /// a set converted from a `Vec`
+    fn set(self, value: AttributeValue) -> AttributeValue {
+        match self.empty_sets {
+            EmptySets::List => empty_set_as_list(value),
+            EmptySets::Omit | EmptySets::Error => value,
+        }
+    }
+
+    /// an element of a list, which cannot be omitted
+    fn element(self, value: AttributeValue) -> AttributeValue {
+        match self.empty_sets {
+            EmptySets::Omit => empty_set_as_list(value),
+            EmptySets::List | EmptySets::Error => value,
+        }
+    }
+
+    /// inserts a field or a value of a map
+    pub fn insert(
+        self,
+        map: &mut HashMap<String, AttributeValue>,
+        key: String,
+        value: AttributeValue,
+    ) {
+        match self.empty_sets {
+            EmptySets::Omit => insert_omitting_empty_set(map, key, value),
+            EmptySets::List | EmptySets::Error => {
+                map.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Conversion of a field type into an attribute.
+/// Implemented once per type, so that every field of the type shares it.
+pub trait ToAttributeValue {
+    fn to_attribute_value(&self, options: Options) -> AttributeValue;
+
+    /// moves the value into the attribute instead of copying it
+    fn into_attribute_value(self, options: Options) -> AttributeValue
+    where
+        Self: Sized;
+
+    /// converts `Vec<Self>`, which is an L unless `Self` is an element type of a set
+    fn vec_to_attribute_value(values: &[Self], options: Options) -> AttributeValue
+    where
+        Self: Sized,
+    {
+        AttributeValue::L(
+            values
+                .iter()
+                .map(|value| options.element(value.to_attribute_value(options)))
+                .collect(),
+        )
+    }
+
+    fn vec_into_attribute_value(values: Vec<Self>, options: Options) -> AttributeValue
+    where
+        Self: Sized,
+    {
+        AttributeValue::L(
+            values
+                .into_iter()
+                .map(|value| options.element(value.into_attribute_value(options)))
+                .collect(),
+        )
+    }
+}
+
+/// Conversion of an attribute into a field type, borrowing `&str` and `&[u8]` for `'de`.
+pub trait FromAttributeValue<'de>: Sized {
+    fn from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        options: Options,
+    ) -> Result<Self, E>;
+
+    /// value of a missing attribute
+    fn from_missing_attribute() -> Result<Self> {
+        Err(DynamoConversionError::missing())
+    }
+
+    /// converts into `Vec<Self>` from an L, unless `Self` is an element type of a set
+    fn vec_from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        options: Options,
+    ) -> Result<Vec<Self>, E> {
+        collect(
+            as_l(value)?.iter().enumerate().map(|(index, value)| {
+                with_index(index, || Self::from_attribute_value(value, options))
+            }),
+        )
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Err(DynamoConversionError::missing())
+    }
+}
+
+/// Conversion of an attribute taken out of the map into a field type.
+pub trait FromOwnedAttributeValue: Sized {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        options: Options,
+    ) -> Result<Self, E>;
+
+    /// value of a missing attribute
+    fn from_missing_attribute() -> Result<Self> {
+        Err(DynamoConversionError::missing())
+    }
+
+    /// converts into `Vec<Self>` from an L, unless `Self` is an element type of a set
+    fn vec_from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        options: Options,
+    ) -> Result<Vec<Self>, E> {
+        collect(
+            into_l(value)?
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    with_index(index, || Self::from_owned_attribute_value(value, options))
+                }),
+        )
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Err(DynamoConversionError::missing())
+    }
+}
+
+/// decodes the attribute of a field, `None` if it is missing
+pub fn decode_field<'de, T: FromAttributeValue<'de>, E: DecodeError>(
+    value: Option<&'de AttributeValue>,
+    options: Options,
+) -> Result<T, E> {
+    match value {
+        Some(value) => T::from_attribute_value(value, options),
+        None => Ok(T::from_missing_attribute()?),
+    }
+}
+
+/// decodes the attribute of a field taken out of the map, `None` if it is missing
+pub fn decode_owned_field<T: FromOwnedAttributeValue, E: DecodeError>(
+    value: Option<AttributeValue>,
+    options: Options,
+) -> Result<T, E> {
+    match value {
+        Some(value) => T::from_owned_attribute_value(value, options),
+        None => Ok(T::from_missing_attribute()?),
+    }
+}
+
+/// decodes the attribute of a field converted by the `from_attribute_value` of its `with` module
+pub fn decode_field_with<T, E: DecodeError>(
+    value: Option<&AttributeValue>,
+    from_attribute_value: impl FnOnce(&AttributeValue) -> Result<T>,
+) -> Result<T, E> {
+    match value {
+        Some(value) => Ok(from_attribute_value(value)?),
+        None => Err(DynamoConversionError::missing().into()),
+    }
+}
+
+/// numbers are written with `to_string` and read with `$parse`.
+/// Floats may format as `NaN`, `inf` or beyond the magnitude of DynamoDB, which `validate_attributes` reports.
+macro_rules! impl_number {
+    ($parse:ident => $( $ty:ty ),*) => {
+        $(
+            impl ToAttributeValue for $ty {
+                fn to_attribute_value(&self, _: Options) -> AttributeValue {
+                    AttributeValue::N(self.to_string())
+                }
+
+                fn into_attribute_value(self, options: Options) -> AttributeValue {
+                    self.to_attribute_value(options)
+                }
+
+                fn vec_to_attribute_value(values: &[Self], options: Options) -> AttributeValue {
+                    options.set(AttributeValue::Ns(values.iter().map(ToString::to_string).collect()))
+                }
+
+                fn vec_into_attribute_value(values: Vec<Self>, options: Options) -> AttributeValue {
+                    Self::vec_to_attribute_value(&values, options)
+                }
+            }
+
+            impl FromAttributeValue<'_> for $ty {
+                fn from_attribute_value<E: DecodeError>(
+                    value: &AttributeValue,
+                    options: Options,
+                ) -> Result<Self, E> {
+                    let n = if options.lenient_numbers {
+                        as_n_lenient(value)?
+                    } else {
+                        as_n(value)?
+                    };
+                    Ok($parse(n)?)
+                }
+
+                fn vec_from_attribute_value<E: DecodeError>(
+                    value: &AttributeValue,
+                    options: Options,
+                ) -> Result<Vec<Self>, E> {
+                    let ns = if options.lenient_numbers {
+                        as_set(value, as_ns_lenient)?
+                    } else {
+                        as_set(value, as_ns)?
+                    };
+                    collect(ns.iter().enumerate().map(|(index, n)| {
+                        with_index(index, || Ok($parse(n)?))
+                    }))
+                }
+
+                fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+                    Ok(vec![])
+                }
+            }
+
+            impl FromOwnedAttributeValue for $ty {
+                fn from_owned_attribute_value<E: DecodeError>(
+                    value: AttributeValue,
+                    options: Options,
+                ) -> Result<Self, E> {
+                    Self::from_attribute_value(&value, options)
+                }
+
+                fn vec_from_owned_attribute_value<E: DecodeError>(
+                    value: AttributeValue,
+                    options: Options,
+                ) -> Result<Vec<Self>, E> {
+                    Self::vec_from_attribute_value(&value, options)
+                }
+
+                fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+                    Ok(vec![])
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(parse_n => i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
+impl_number!(parse_float => f32, f64);
+
+impl ToAttributeValue for String {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::S(self.clone())
+    }
+
+    fn into_attribute_value(self, _: Options) -> AttributeValue {
+        AttributeValue::S(self)
+    }
+
+    fn vec_to_attribute_value(values: &[Self], options: Options) -> AttributeValue {
+        options.set(AttributeValue::Ss(values.to_vec()))
+    }
+
+    fn vec_into_attribute_value(values: Vec<Self>, options: Options) -> AttributeValue {
+        options.set(AttributeValue::Ss(values))
+    }
+}
+
+impl FromAttributeValue<'_> for String {
+    fn from_attribute_value<E: DecodeError>(value: &AttributeValue, _: Options) -> Result<Self, E> {
+        Ok(as_s(value)?.clone())
+    }
+
+    fn vec_from_attribute_value<E: DecodeError>(
+        value: &AttributeValue,
+        _: Options,
+    ) -> Result<Vec<Self>, E> {
+        Ok(as_set(value, as_ss)?.to_vec())
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Ok(vec![])
+    }
+}
+
+impl FromOwnedAttributeValue for String {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        Ok(into_s(value)?)
+    }
+
+    fn vec_from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Vec<Self>, E> {
+        Ok(into_set(value, into_ss)?)
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Ok(vec![])
+    }
+}
+
+impl ToAttributeValue for &str {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::S(self.to_string())
+    }
+
+    fn into_attribute_value(self, options: Options) -> AttributeValue {
+        self.to_attribute_value(options)
+    }
+
+    fn vec_to_attribute_value(values: &[Self], options: Options) -> AttributeValue {
+        options.set(AttributeValue::Ss(
+            values.iter().map(ToString::to_string).collect(),
+        ))
+    }
+
+    fn vec_into_attribute_value(values: Vec<Self>, options: Options) -> AttributeValue {
+        Self::vec_to_attribute_value(&values, options)
+    }
+}
+
+impl<'de> FromAttributeValue<'de> for &'de str {
+    fn from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        Ok(as_s(value)?.as_str())
+    }
+
+    fn vec_from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        _: Options,
+    ) -> Result<Vec<Self>, E> {
+        Ok(as_set(value, as_ss)?.iter().map(String::as_str).collect())
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Ok(vec![])
+    }
+}
+
+impl ToAttributeValue for Blob {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::B(self.clone())
+    }
+
+    fn into_attribute_value(self, _: Options) -> AttributeValue {
+        AttributeValue::B(self)
+    }
+
+    fn vec_to_attribute_value(values: &[Self], options: Options) -> AttributeValue {
+        options.set(AttributeValue::Bs(values.to_vec()))
+    }
+
+    fn vec_into_attribute_value(values: Vec<Self>, options: Options) -> AttributeValue {
+        options.set(AttributeValue::Bs(values))
+    }
+}
+
+impl FromAttributeValue<'_> for Blob {
+    fn from_attribute_value<E: DecodeError>(value: &AttributeValue, _: Options) -> Result<Self, E> {
+        Ok(as_b(value)?.clone())
+    }
+
+    fn vec_from_attribute_value<E: DecodeError>(
+        value: &AttributeValue,
+        _: Options,
+    ) -> Result<Vec<Self>, E> {
+        Ok(as_set(value, as_bs)?.to_vec())
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Ok(vec![])
+    }
+}
+
+impl FromOwnedAttributeValue for Blob {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        Ok(into_b(value)?)
+    }
+
+    fn vec_from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Vec<Self>, E> {
+        Ok(into_set(value, into_bs)?)
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Ok(vec![])
+    }
+}
+
+impl ToAttributeValue for &[u8] {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::B(Blob::new(self.to_vec()))
+    }
+
+    fn into_attribute_value(self, options: Options) -> AttributeValue {
+        self.to_attribute_value(options)
+    }
+
+    fn vec_to_attribute_value(values: &[Self], options: Options) -> AttributeValue {
+        options.set(AttributeValue::Bs(
+            values
+                .iter()
+                .map(|value| Blob::new(value.to_vec()))
+                .collect(),
+        ))
+    }
+
+    fn vec_into_attribute_value(values: Vec<Self>, options: Options) -> AttributeValue {
+        Self::vec_to_attribute_value(&values, options)
+    }
+}
+
+impl<'de> FromAttributeValue<'de> for &'de [u8] {
+    fn from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        Ok(as_b(value)?.as_ref())
+    }
+
+    fn vec_from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        _: Options,
+    ) -> Result<Vec<Self>, E> {
+        Ok(as_set(value, as_bs)?.iter().map(Blob::as_ref).collect())
+    }
+
+    fn vec_from_missing_attribute() -> Result<Vec<Self>> {
+        Ok(vec![])
+    }
+}
+
+impl ToAttributeValue for bool {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::Bool(*self)
+    }
+
+    fn into_attribute_value(self, options: Options) -> AttributeValue {
+        self.to_attribute_value(options)
+    }
+}
+
+impl FromAttributeValue<'_> for bool {
+    fn from_attribute_value<E: DecodeError>(value: &AttributeValue, _: Options) -> Result<Self, E> {
+        Ok(*as_bool(value)?)
+    }
+}
+
+impl FromOwnedAttributeValue for bool {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        Ok(into_bool(value)?)
+    }
+}
+
+/// `None` is a NULL attribute
+impl ToAttributeValue for Option<()> {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::Null(self.is_none())
+    }
+
+    fn into_attribute_value(self, options: Options) -> AttributeValue {
+        self.to_attribute_value(options)
+    }
+}
+
+impl FromAttributeValue<'_> for Option<()> {
+    fn from_attribute_value<E: DecodeError>(value: &AttributeValue, _: Options) -> Result<Self, E> {
+        Ok((!*as_null(value)?).then_some(()))
+    }
+}
+
+impl FromOwnedAttributeValue for Option<()> {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        Ok((!into_null(value)?).then_some(()))
+    }
+}
+
+impl<T: ToAttributeValue> ToAttributeValue for Vec<T> {
+    fn to_attribute_value(&self, options: Options) -> AttributeValue {
+        T::vec_to_attribute_value(self, options)
+    }
+
+    fn into_attribute_value(self, options: Options) -> AttributeValue {
+        T::vec_into_attribute_value(self, options)
+    }
+}
+
+impl<'de, T: FromAttributeValue<'de>> FromAttributeValue<'de> for Vec<T> {
+    fn from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        options: Options,
+    ) -> Result<Self, E> {
+        T::vec_from_attribute_value(value, options)
+    }
+
+    fn from_missing_attribute() -> Result<Self> {
+        T::vec_from_missing_attribute()
+    }
+}
+
+impl<T: FromOwnedAttributeValue> FromOwnedAttributeValue for Vec<T> {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        options: Options,
+    ) -> Result<Self, E> {
+        T::vec_from_owned_attribute_value(value, options)
+    }
+
+    fn from_missing_attribute() -> Result<Self> {
+        T::vec_from_missing_attribute()
+    }
+}
+
+impl<T: ToAttributeValue> ToAttributeValue for HashMap<String, T> {
+    fn to_attribute_value(&self, options: Options) -> AttributeValue {
+        let mut map = HashMap::with_capacity(self.len());
+        for (key, value) in self {
+            options.insert(&mut map, key.clone(), value.to_attribute_value(options));
+        }
+        AttributeValue::M(map)
+    }
+
+    fn into_attribute_value(self, options: Options) -> AttributeValue {
+        let mut map = HashMap::with_capacity(self.len());
+        for (key, value) in self {
+            options.insert(&mut map, key, value.into_attribute_value(options));
+        }
+        AttributeValue::M(map)
+    }
+}
+
+impl<'de, T: FromAttributeValue<'de>> FromAttributeValue<'de> for HashMap<String, T> {
+    fn from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        options: Options,
+    ) -> Result<Self, E> {
+        collect(as_m(value)?.iter().map(|(key, value)| {
+            with_key(key, || {
+                Ok((key.clone(), T::from_attribute_value(value, options)?))
+            })
+        }))
+    }
+}
+
+impl<T: FromOwnedAttributeValue> FromOwnedAttributeValue for HashMap<String, T> {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        options: Options,
+    ) -> Result<Self, E> {
+        collect(into_m(value)?.into_iter().map(|(key, value)| {
+            let value = with_key(&key, || T::from_owned_attribute_value(value, options))?;
+            Ok((key, value))
+        }))
+    }
+}
+
+/// nested items are converted by their own options
+impl<T: ToAttributeMap> ToAttributeValue for T {
+    fn to_attribute_value(&self, _: Options) -> AttributeValue {
+        AttributeValue::M(self.to_attribute_map())
+    }
+
+    fn into_attribute_value(self, _: Options) -> AttributeValue {
+        AttributeValue::M(self.into_attribute_map())
+    }
+}
+
+impl<'de, T: FromAttributeMap<'de>> FromAttributeValue<'de> for T {
+    fn from_attribute_value<E: DecodeError>(
+        value: &'de AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        T::from_attribute_map(as_m(value)?)
+    }
+}
+
+impl<T: FromOwnedAttributeMap> FromOwnedAttributeValue for T {
+    fn from_owned_attribute_value<E: DecodeError>(
+        value: AttributeValue,
+        _: Options,
+    ) -> Result<Self, E> {
+        T::from_owned_attribute_map(into_m(value)?)
+    }
+}
+
+#[cfg(test)]
+mod test_private {
+    use crate::__private::{check_n, normalize_integer as normalize};
+
+    #[test]
+    fn normalize_integers() {
+        assert_eq!(normalize("42").as_deref(), Some("42"));
+        assert_eq!(normalize("-0042").as_deref(), Some("-42"));
+        assert_eq!(normalize("1E+2").as_deref(), Some("100"));
+        assert_eq!(normalize("100.0").as_deref(), Some("100"));
+        assert_eq!(normalize("1.50e1").as_deref(), Some("15"));
+        assert_eq!(normalize("1200e-2").as_deref(), Some("12"));
+        assert_eq!(normalize("-0.0").as_deref(), Some("0"));
+        assert_eq!(normalize("0.1"), None);
+        assert_eq!(normalize("1e"), None);
+        assert_eq!(normalize("abc"), None);
+        assert_eq!(normalize("."), None);
+        assert_eq!(normalize(" 1"), None);
+        assert_eq!(normalize("1e99999").map(|n| n.len()), Some(41));
+    }
+
+    #[test]
+    fn check_numbers() {
+        assert!(check_n("12345678901234567890123456789012345678").is_ok());
+        assert!(check_n("-1.2345678901234567890123456789012345678E+50").is_ok());
+        assert!(check_n("1000000000000000000000000000000000000000000").is_ok());
+        assert!(check_n("0.000").is_ok());
+        assert!(check_n("1E-130").is_ok());
+        assert!(check_n("9.9E+125").is_ok());
+        assert_eq!(
+            check_n("340282366920938463463374607431768211455").unwrap_err(),
+            "`340282366920938463463374607431768211455` has 39 significant digits, \
+            more than the 38 DynamoDB supports"
+        );
+        assert!(check_n("1E-131").is_err());
+        assert!(check_n("1E+126").is_err());
+        assert!(check_n("NaN").is_err());
+    }
+}
@@ -1,3 +0,0 @@
-pub const AWS_DYNAMO_ATTR_META_ENTRY: &str = "aws_dynamo";
-pub const KEY_TABLE_NAME: &str = "table_name";
-pub const PRIMARY_KEY_INPUT_STRUCT_POSTFIX: &str = "PrimaryKey";
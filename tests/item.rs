@@ -1,4 +1,6 @@
-use aws_dynamo_derive::{Item, Table};
+use aws_dynamo_derive::{
+    AttributePathSegment, AttributeValueType, DynamoConversionErrorKind, Item, Table,
+};
 use aws_sdk_dynamodb::types::AttributeValue;
 use aws_sdk_dynamodb::Client;
 use std::collections::HashMap;
@@ -12,7 +14,7 @@ async fn test_conversions() {
         inner: Vec<Inner>,
     }
 
-    #[derive(Item, Clone, Debug, Eq, PartialEq)]
+    #[derive(Item, Debug, Eq, PartialEq)]
     struct Inner {
         name: String,
         value: u32,
@@ -51,3 +53,888 @@ async fn test_conversions() {
     assert_eq!(outer, expected_outer);
     assert_eq!(expected_outer, outer2);
 }
+
+#[tokio::test]
+async fn test_other_attributes() {
+    #[derive(Table, Debug, PartialEq)]
+    struct Outer {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        inner: Inner,
+        #[aws_dynamo(other)]
+        extra: HashMap<String, AttributeValue>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    struct Inner {
+        name: String,
+        #[aws_dynamo(other)]
+        extra: HashMap<String, AttributeValue>,
+    }
+
+    let mut inner_map = HashMap::new();
+    inner_map.insert("Name".to_string(), AttributeValue::S("foo".to_string()));
+    inner_map.insert("Unknown".to_string(), AttributeValue::Bool(true));
+
+    let mut map = HashMap::new();
+    map.insert("Hk".to_string(), AttributeValue::S("abc".to_string()));
+    map.insert("Inner".to_string(), AttributeValue::M(inner_map));
+    map.insert("Written".to_string(), AttributeValue::N("7".to_string()));
+
+    let outer = Outer::from_attribute_value(&map).unwrap();
+    assert_eq!(
+        outer.extra,
+        HashMap::from([("Written".to_string(), AttributeValue::N("7".to_string()))])
+    );
+    assert_eq!(
+        outer.inner.extra,
+        HashMap::from([("Unknown".to_string(), AttributeValue::Bool(true))])
+    );
+
+    let converted: HashMap<String, AttributeValue> = (&outer).into();
+    assert_eq!(converted, map);
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    let builder = outer.put_item(client.put_item());
+    assert_eq!(builder.get_item().as_ref().unwrap(), &map);
+
+    let outer2: Outer = map.try_into().unwrap();
+    assert_eq!(outer, outer2);
+}
+
+#[test]
+fn test_deny_unknown_attributes() {
+    #[derive(Table, Debug, Eq, PartialEq)]
+    #[aws_dynamo(deny_unknown_attributes)]
+    struct Strict {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+    }
+
+    #[derive(Item, Debug, Eq, PartialEq)]
+    #[aws_dynamo(deny_unknown_attributes(allow = ["Ttl"]))]
+    struct Tolerant {
+        name: String,
+    }
+
+    let mut map = HashMap::new();
+    map.insert("Hk".to_string(), AttributeValue::S("abc".to_string()));
+    assert!(Strict::from_attribute_value(&map).is_ok());
+
+    map.insert("Zzz".to_string(), AttributeValue::Bool(true));
+    map.insert("Aaa".to_string(), AttributeValue::Bool(true));
+    let err = Strict::from_attribute_value(&map).unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::UnknownAttributes);
+    assert_eq!(err.unknown_attribute_names(), ["Aaa", "Zzz"]);
+    assert_eq!(Strict::try_from(&map), Err(err));
+
+    let mut map = HashMap::new();
+    map.insert("Name".to_string(), AttributeValue::S("foo".to_string()));
+    map.insert("Ttl".to_string(), AttributeValue::N("1".to_string()));
+    assert!(Tolerant::try_from(&map).is_ok());
+
+    map.insert("Typo".to_string(), AttributeValue::S("foo".to_string()));
+    assert_eq!(
+        Tolerant::try_from(map).unwrap_err().to_string(),
+        "unknown attributes: `Typo`"
+    );
+}
+
+#[test]
+fn test_conversion_error() {
+    #[derive(Table, Debug)]
+    struct Outer {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        inner: Vec<Inner>,
+    }
+
+    #[derive(Item, Debug)]
+    struct Inner {
+        value: u32,
+    }
+
+    let inner = |value| HashMap::from([("Value".to_string(), value)]);
+    let outer = |inner_values: Vec<AttributeValue>| {
+        HashMap::from([
+            ("Hk".to_string(), AttributeValue::S("abc".to_string())),
+            (
+                "Inner".to_string(),
+                AttributeValue::L(
+                    inner_values
+                        .into_iter()
+                        .map(|v| AttributeValue::M(inner(v)))
+                        .collect(),
+                ),
+            ),
+        ])
+    };
+
+    let err = Outer::from_attribute_value(&outer(vec![
+        AttributeValue::N("1".to_string()),
+        AttributeValue::S("abc".to_string()),
+    ]))
+    .unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::TypeMismatch);
+    assert_eq!(
+        err.path(),
+        [
+            AttributePathSegment::Key("Inner".to_string()),
+            AttributePathSegment::Index(1),
+            AttributePathSegment::Key("Value".to_string()),
+        ]
+    );
+    assert_eq!(err.expected(), Some(AttributeValueType::N));
+    assert_eq!(err.actual(), Some(AttributeValueType::S));
+    assert_eq!(err.value(), Some(&AttributeValue::S("abc".to_string())));
+    assert_eq!(
+        err.to_string(),
+        "type mismatch at `Inner[1].Value`: expected N, found S"
+    );
+
+    let err =
+        Outer::from_attribute_value(&outer(vec![AttributeValue::N("-1".to_string())])).unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::ParseFailure);
+    assert_eq!(err.path_string(), "Inner[0].Value");
+
+    let mut map = outer(vec![]);
+    map.remove("Hk");
+    let err: Result<Outer, _> = map.try_into();
+    assert_eq!(err.unwrap_err().to_string(), "missing attribute at `Hk`");
+}
+
+#[test]
+fn test_all_errors() {
+    #[derive(Table, Debug)]
+    struct Outer {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        count: u32,
+        inner: Vec<Inner>,
+        values: HashMap<String, u8>,
+    }
+
+    #[derive(Item, Debug)]
+    struct Inner {
+        name: String,
+        value: u32,
+    }
+
+    let inner = |name: AttributeValue, value: AttributeValue| {
+        AttributeValue::M(HashMap::from([
+            ("Name".to_string(), name),
+            ("Value".to_string(), value),
+        ]))
+    };
+    let mut map = HashMap::from([
+        ("Hk".to_string(), AttributeValue::S("abc".to_string())),
+        (
+            "Inner".to_string(),
+            AttributeValue::L(vec![
+                inner(
+                    AttributeValue::N("1".to_string()),
+                    AttributeValue::N("1".to_string()),
+                ),
+                inner(
+                    AttributeValue::S("ok".to_string()),
+                    AttributeValue::N("1".to_string()),
+                ),
+                inner(
+                    AttributeValue::S("ok".to_string()),
+                    AttributeValue::N("x".to_string()),
+                ),
+            ]),
+        ),
+        (
+            "Values".to_string(),
+            AttributeValue::M(HashMap::from([(
+                "a".to_string(),
+                AttributeValue::N("256".to_string()),
+            )])),
+        ),
+    ]);
+
+    let errors = Outer::try_from_attribute_value_all_errors(&map).unwrap_err();
+    let mut paths = errors
+        .errors()
+        .iter()
+        .map(|e| (e.path_string(), e.kind()))
+        .collect::<Vec<_>>();
+    paths.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        paths,
+        vec![
+            ("Count".to_string(), DynamoConversionErrorKind::Missing),
+            (
+                "Inner[0].Name".to_string(),
+                DynamoConversionErrorKind::TypeMismatch
+            ),
+            (
+                "Inner[2].Value".to_string(),
+                DynamoConversionErrorKind::ParseFailure
+            ),
+            (
+                "Values.a".to_string(),
+                DynamoConversionErrorKind::ParseFailure
+            ),
+        ]
+    );
+
+    // fail-fast conversion reports only one of them
+    assert!(Outer::from_attribute_value(&map).is_err());
+
+    map.insert("Count".to_string(), AttributeValue::N("3".to_string()));
+    map.insert("Inner".to_string(), AttributeValue::L(vec![]));
+    map.insert("Values".to_string(), AttributeValue::M(HashMap::new()));
+    let outer = Outer::try_from_attribute_value_all_errors(&map).unwrap();
+    assert_eq!(outer.count, 3);
+}
+
+#[test]
+fn test_normalized_numbers() {
+    #[derive(Item, Debug, PartialEq)]
+    struct Numbers {
+        value: u8,
+        values: Vec<i64>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    #[aws_dynamo(lenient_numbers)]
+    struct LegacyNumbers {
+        value: u8,
+        values: Vec<i64>,
+    }
+
+    let numbers = |value: AttributeValue, values: AttributeValue| {
+        HashMap::from([("Value".to_string(), value), ("Values".to_string(), values)])
+    };
+
+    let map = numbers(
+        AttributeValue::N("1E+2".to_string()),
+        AttributeValue::Ns(vec!["100.0".to_string(), "-2.5e1".to_string()]),
+    );
+    let decoded: Numbers = map.try_into().unwrap();
+    assert_eq!(
+        decoded,
+        Numbers {
+            value: 100,
+            values: vec![100, -25],
+        }
+    );
+
+    let map = numbers(
+        AttributeValue::N("2.56E2".to_string()),
+        AttributeValue::Ns(vec!["1.5".to_string()]),
+    );
+    let errors = Numbers::try_from_attribute_value_all_errors(&map)
+        .unwrap_err()
+        .into_errors()
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "failed to parse N at `Value`: `2.56E2` is out of range for `u8` (value: N(\"2.56E2\"))",
+            "failed to parse N at `Values[0]`: `1.5` is not an integer (value: N(\"1.5\"))",
+        ]
+    );
+
+    let map = numbers(
+        AttributeValue::N("-1".to_string()),
+        AttributeValue::Ns(vec!["-1".to_string()]),
+    );
+    let err = Numbers::try_from(&map).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to parse N at `Value`: `-1` is out of range for `u8` (value: N(\"-1\"))"
+    );
+
+    let map = numbers(
+        AttributeValue::S("7".to_string()),
+        AttributeValue::Ss(vec!["8".to_string()]),
+    );
+    let err = Numbers::try_from(&map).unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::TypeMismatch);
+    let decoded: LegacyNumbers = map.try_into().unwrap();
+    assert_eq!(
+        decoded,
+        LegacyNumbers {
+            value: 7,
+            values: vec![8],
+        }
+    );
+}
+
+#[test]
+fn test_number_range() {
+    #[derive(Table, Debug)]
+    struct Wide {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        values: Vec<u128>,
+        inner: Vec<WideInner>,
+    }
+
+    #[derive(Item, Debug)]
+    struct WideInner {
+        value: i128,
+    }
+
+    let mut wide = Wide {
+        hk: "hk".to_string(),
+        values: vec![1, u128::MAX],
+        inner: vec![WideInner { value: i128::MAX }],
+    };
+    let err = wide.try_to_attribute_value().unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::OutOfRange);
+    assert!(["Values[1]", "Inner[0].Value"].contains(&err.path_string().as_str()));
+
+    wide.values = vec![1, 10u128.pow(37)];
+    let err = wide.try_to_attribute_value().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "number out of range at `Inner[0].Value`: `170141183460469231731687303715884105727` \
+        has 39 significant digits, more than the 38 DynamoDB supports"
+    );
+
+    wide.inner = vec![WideInner {
+        value: -(10i128.pow(37)),
+    }];
+    let map = wide.try_to_attribute_value().unwrap();
+    assert_eq!(map, HashMap::from(&wide));
+}
+
+#[test]
+fn test_floats() {
+    #[derive(Item, Debug, PartialEq)]
+    struct Measure {
+        ratio: f32,
+        value: f64,
+        values: Vec<f64>,
+    }
+
+    let measure = Measure {
+        ratio: 0.5,
+        value: -1.25e-5,
+        values: vec![0.1, 1e20],
+    };
+    let map = measure.try_to_attribute_value().unwrap();
+    assert_eq!(map["Ratio"], AttributeValue::N("0.5".to_string()));
+    assert_eq!(map["Value"], AttributeValue::N("-0.0000125".to_string()));
+    assert_eq!(
+        map["Values"],
+        AttributeValue::Ns(vec!["0.1".to_string(), "100000000000000000000".to_string()])
+    );
+    assert_eq!(Measure::try_from(&map).unwrap(), measure);
+
+    let mut map = map;
+    map.insert("Value".to_string(), AttributeValue::N("1E+2".to_string()));
+    assert_eq!(Measure::try_from(&map).unwrap().value, 100.0);
+
+    map.insert("Ratio".to_string(), AttributeValue::N("1E+39".to_string()));
+    map.insert("Value".to_string(), AttributeValue::N("NaN".to_string()));
+    let errors = Measure::try_from_attribute_value_all_errors(&map)
+        .unwrap_err()
+        .into_errors()
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            "failed to parse N at `Ratio`: `1E+39` is out of range for `f32` (value: N(\"1E+39\"))",
+            "failed to parse N at `Value`: `NaN` is not a number (value: N(\"NaN\"))",
+        ]
+    );
+
+    // DynamoDB has no `NaN` nor infinity, and keeps numbers up to 38 digits and exponents of -130..=125
+    for (value, message) in [
+        (f64::NAN, "`NaN` is not a number"),
+        (f64::NEG_INFINITY, "`-inf` is not a number"),
+        (1e200, "is beyond the magnitude DynamoDB supports"),
+        (1e-200, "is beyond the magnitude DynamoDB supports"),
+    ] {
+        let measure = Measure {
+            ratio: 0.0,
+            value,
+            values: vec![],
+        };
+        let err = measure.try_to_attribute_value().unwrap_err();
+        assert_eq!(err.kind(), DynamoConversionErrorKind::OutOfRange);
+        assert_eq!(err.path_string(), "Value");
+        assert!(err.to_string().contains(message), "{err}");
+    }
+}
+
+#[test]
+fn test_empty_sets() {
+    #[derive(Table, Debug, PartialEq)]
+    struct Omitted {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        tags: Vec<String>,
+        nested: Vec<Vec<u32>>,
+        by_name: HashMap<String, Vec<String>>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    #[aws_dynamo(empty_sets = "list")]
+    struct Listed {
+        tags: Vec<String>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    #[aws_dynamo(empty_sets = "error")]
+    struct Kept {
+        tags: Vec<String>,
+    }
+
+    let omitted = Omitted {
+        hk: "hk".to_string(),
+        tags: vec![],
+        nested: vec![vec![]],
+        by_name: HashMap::from([("a".to_string(), vec![])]),
+    };
+    let map = omitted.try_to_attribute_value().unwrap();
+    assert_eq!(
+        map,
+        HashMap::from([
+            ("Hk".to_string(), AttributeValue::S("hk".to_string())),
+            (
+                "Nested".to_string(),
+                AttributeValue::L(vec![AttributeValue::L(vec![])])
+            ),
+            ("ByName".to_string(), AttributeValue::M(HashMap::new())),
+        ])
+    );
+    // map entries holding an empty set are omitted as well
+    let decoded = Omitted::try_from(map).unwrap();
+    assert!(decoded.tags.is_empty() && decoded.by_name.is_empty());
+    assert_eq!(decoded.nested, omitted.nested);
+
+    let map = HashMap::from(Listed { tags: vec![] });
+    assert_eq!(map["Tags"], AttributeValue::L(vec![]));
+    assert_eq!(Listed::try_from(map).unwrap(), Listed { tags: vec![] });
+
+    let kept = Kept { tags: vec![] };
+    assert_eq!(HashMap::from(&kept)["Tags"], AttributeValue::Ss(vec![]));
+    let err = kept.try_to_attribute_value().unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::EmptySet);
+    assert_eq!(err.to_string(), "empty SS at `Tags`");
+
+    let empty_key = Omitted {
+        hk: String::new(),
+        ..omitted
+    };
+    let err = empty_key.try_to_attribute_value().unwrap_err();
+    assert_eq!(err.kind(), DynamoConversionErrorKind::EmptyKey);
+    assert_eq!(err.to_string(), "empty key attribute at `Hk`");
+}
+
+#[test]
+fn test_generics() {
+    #[derive(Table, Debug, PartialEq)]
+    struct Envelope<T, const VERSION: u8>
+    where
+        T: std::fmt::Debug,
+    {
+        #[aws_dynamo(hash_key)]
+        id: String,
+        payload: T,
+        history: Vec<T>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    struct Payload<T: std::fmt::Debug> {
+        value: T,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    struct Count {
+        count: u32,
+    }
+
+    let envelope = Envelope::<Payload<Count>, 1> {
+        id: "id".to_string(),
+        payload: Payload {
+            value: Count { count: 1 },
+        },
+        history: vec![Payload {
+            value: Count { count: 0 },
+        }],
+    };
+    let map = envelope.try_to_attribute_value().unwrap();
+    assert_eq!(
+        map["Payload"],
+        AttributeValue::M(HashMap::from([(
+            "Value".to_string(),
+            AttributeValue::M(HashMap::from([(
+                "Count".to_string(),
+                AttributeValue::N("1".to_string())
+            )]))
+        )]))
+    );
+    assert_eq!(
+        Envelope::<Payload<Count>, 1>::from_attribute_value(&map).unwrap(),
+        envelope
+    );
+    assert_eq!(Envelope::<Payload<Count>, 1>::get_table_name(), "Envelope");
+}
+
+#[tokio::test]
+async fn test_put_item_keeps_builder_attributes() {
+    #[derive(Table, Debug, PartialEq)]
+    struct Versioned {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        version: u32,
+    }
+
+    let versioned = || Versioned {
+        hk: "hk".to_string(),
+        version: 2,
+    };
+    let expected = HashMap::from([
+        ("Hk".to_string(), AttributeValue::S("hk".to_string())),
+        ("Version".to_string(), AttributeValue::N("2".to_string())),
+        ("Ttl".to_string(), AttributeValue::N("60".to_string())),
+    ]);
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    // attributes put beforehand are kept, and overwritten by fields of the same name
+    let builder = || {
+        client
+            .put_item()
+            .item("Ttl", AttributeValue::N("60".to_string()))
+            .item("Version", AttributeValue::N("1".to_string()))
+    };
+
+    let put_item = versioned().put_item(builder());
+    assert_eq!(put_item.get_item().as_ref(), Some(&expected));
+    let into_put_item = versioned().into_put_item(builder());
+    assert_eq!(into_put_item.get_item().as_ref(), Some(&expected));
+    let try_put_item = versioned().try_put_item(builder()).unwrap();
+    assert_eq!(try_put_item.get_item().as_ref(), Some(&expected));
+}
+
+#[test]
+fn test_generic_scalar_fields() {
+    #[derive(Item, Debug, PartialEq)]
+    struct Envelope<T> {
+        id: String,
+        payload: T,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    struct Inner {
+        name: String,
+    }
+
+    let envelope = Envelope {
+        id: "id".to_string(),
+        payload: "text".to_string(),
+    };
+    let map = HashMap::from(&envelope);
+    assert_eq!(map["Payload"], AttributeValue::S("text".to_string()));
+    assert_eq!(Envelope::try_from(&map).unwrap(), envelope);
+    assert_eq!(Envelope::try_from(map).unwrap(), envelope);
+
+    let envelope = Envelope {
+        id: "id".to_string(),
+        payload: 7u32,
+    };
+    let map = HashMap::from(&envelope);
+    assert_eq!(map["Payload"], AttributeValue::N("7".to_string()));
+    assert_eq!(Envelope::try_from(&map).unwrap(), envelope);
+    assert_eq!(Envelope::try_from(map).unwrap(), envelope);
+
+    let envelope = Envelope {
+        id: "id".to_string(),
+        payload: Inner {
+            name: "inner".to_string(),
+        },
+    };
+    let map = HashMap::from(&envelope);
+    assert_eq!(
+        map["Payload"],
+        AttributeValue::M(HashMap::from([(
+            "Name".to_string(),
+            AttributeValue::S("inner".to_string())
+        )]))
+    );
+    assert_eq!(Envelope::try_from(&map).unwrap(), envelope);
+    assert_eq!(Envelope::try_from(map).unwrap(), envelope);
+}
+
+#[test]
+fn test_nested_without_clone() {
+    #[derive(Item, Debug, PartialEq)]
+    struct Outer {
+        inner: Inner,
+        list: Vec<Inner>,
+        by_name: HashMap<String, Inner>,
+    }
+
+    // neither derives nor implements Clone
+    #[derive(Item, Debug, PartialEq)]
+    struct Inner {
+        buffer: Vec<String>,
+    }
+
+    let inner = || Inner {
+        buffer: vec!["a".to_string()],
+    };
+    let outer = Outer {
+        inner: inner(),
+        list: vec![inner(), inner()],
+        by_name: HashMap::from([("a".to_string(), inner())]),
+    };
+
+    let map = HashMap::from(&outer);
+    let inner_map = HashMap::from([(
+        "Buffer".to_string(),
+        AttributeValue::Ss(vec!["a".to_string()]),
+    )]);
+    assert_eq!(map["Inner"], AttributeValue::M(inner_map.clone()));
+    assert_eq!(
+        map["List"],
+        AttributeValue::L(vec![
+            AttributeValue::M(inner_map.clone()),
+            AttributeValue::M(inner_map)
+        ])
+    );
+    assert_eq!(Outer::try_from(map).unwrap(), outer);
+}
+
+#[tokio::test]
+async fn test_into_item() {
+    use aws_sdk_dynamodb::primitives::Blob;
+
+    #[derive(Table, Debug, PartialEq)]
+    struct Owned {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        blob: Blob,
+        flag: bool,
+        nothing: Option<()>,
+        tags: Vec<String>,
+        numbers: Vec<u32>,
+        blobs: Vec<Blob>,
+        nested: Vec<Vec<String>>,
+        by_name: HashMap<String, Inner>,
+        inner: Inner,
+        #[aws_dynamo(other)]
+        other: HashMap<String, AttributeValue>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    struct Inner {
+        name: String,
+    }
+
+    let owned = || Owned {
+        hk: "hk".to_string(),
+        blob: Blob::new("blob"),
+        flag: true,
+        nothing: None,
+        tags: vec!["a".to_string(), "b".to_string()],
+        numbers: vec![1, 2],
+        blobs: vec![Blob::new("b")],
+        nested: vec![vec!["c".to_string()], vec![]],
+        by_name: HashMap::from([(
+            "x".to_string(),
+            Inner {
+                name: "x".to_string(),
+            },
+        )]),
+        inner: Inner {
+            name: "inner".to_string(),
+        },
+        other: HashMap::from([("Extra".to_string(), AttributeValue::Bool(false))]),
+    };
+
+    let expected = HashMap::from(&owned());
+    assert_eq!(owned().into_item(), expected);
+    assert_eq!(HashMap::from(owned()), expected);
+    assert_eq!(Owned::try_from(owned().into_item()).unwrap(), owned());
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    let builder = owned().into_put_item(client.put_item());
+    assert_eq!(builder.get_item().as_ref(), Some(&expected));
+    assert_eq!(builder.get_table_name().as_deref(), Some("Owned"));
+}
+
+#[test]
+fn test_owned_decode() {
+    #[derive(Table, Debug, PartialEq)]
+    #[aws_dynamo(lenient_numbers)]
+    struct Page {
+        #[aws_dynamo(hash_key)]
+        hk: String,
+        count: u8,
+        numbers: Vec<i64>,
+        missing: Vec<String>,
+        rows: Vec<Row>,
+        by_name: HashMap<String, Vec<u16>>,
+        #[aws_dynamo(other)]
+        other: HashMap<String, AttributeValue>,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    #[aws_dynamo(deny_unknown_attributes)]
+    struct Row {
+        value: u32,
+    }
+
+    let row = |value: &str| {
+        AttributeValue::M(HashMap::from([(
+            "Value".to_string(),
+            AttributeValue::N(value.to_string()),
+        )]))
+    };
+    let mut map = HashMap::from([
+        ("Hk".to_string(), AttributeValue::S("hk".to_string())),
+        ("Count".to_string(), AttributeValue::S("1E+1".to_string())),
+        (
+            "Numbers".to_string(),
+            AttributeValue::Ns(vec!["-1".to_string(), "2".to_string()]),
+        ),
+        (
+            "Rows".to_string(),
+            AttributeValue::L(vec![row("1"), row("2")]),
+        ),
+        (
+            "ByName".to_string(),
+            AttributeValue::M(HashMap::from([(
+                "x".to_string(),
+                AttributeValue::Ss(vec!["3".to_string()]),
+            )])),
+        ),
+        ("Extra".to_string(), AttributeValue::Bool(true)),
+    ]);
+
+    let page = Page::try_from(map.clone()).unwrap();
+    assert_eq!(page, Page::try_from(&map).unwrap());
+    assert_eq!(page.count, 10);
+    assert_eq!(page.missing, Vec::<String>::new());
+    assert_eq!(
+        page.other,
+        HashMap::from([("Extra".to_string(), AttributeValue::Bool(true))])
+    );
+
+    map.insert(
+        "Rows".to_string(),
+        AttributeValue::L(vec![row("1"), row("x")]),
+    );
+    let err = Page::try_from(map.clone()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        Page::try_from(&map).unwrap_err().to_string()
+    );
+    assert_eq!(
+        err.path(),
+        [
+            AttributePathSegment::Key("Rows".to_string()),
+            AttributePathSegment::Index(1),
+            AttributePathSegment::Key("Value".to_string()),
+        ]
+    );
+
+    let mut row = HashMap::from([
+        ("Value".to_string(), AttributeValue::N("1".to_string())),
+        ("Typo".to_string(), AttributeValue::N("1".to_string())),
+    ]);
+    assert_eq!(
+        Row::try_from(row.clone()).unwrap_err().kind(),
+        DynamoConversionErrorKind::UnknownAttributes
+    );
+    row.remove("Typo");
+    assert_eq!(Row::try_from(row).unwrap(), Row { value: 1 });
+}
+
+#[test]
+fn test_borrowed_decode() {
+    #[derive(Table, Debug, PartialEq)]
+    struct View<'a> {
+        #[aws_dynamo(hash_key)]
+        name: &'a str,
+        tags: Vec<&'a str>,
+        data: &'a [u8],
+        chunks: Vec<&'a [u8]>,
+        by_name: HashMap<String, &'a str>,
+        inner: Inner<'a>,
+        count: u32,
+    }
+
+    #[derive(Item, Debug, PartialEq)]
+    struct Inner<'a> {
+        id: &'a str,
+    }
+
+    use aws_sdk_dynamodb::primitives::Blob;
+
+    let map = HashMap::from([
+        ("Name".to_string(), AttributeValue::S("foo".to_string())),
+        (
+            "Tags".to_string(),
+            AttributeValue::Ss(vec!["a".to_string(), "b".to_string()]),
+        ),
+        ("Data".to_string(), AttributeValue::B(Blob::new("data"))),
+        (
+            "Chunks".to_string(),
+            AttributeValue::Bs(vec![Blob::new("c")]),
+        ),
+        (
+            "ByName".to_string(),
+            AttributeValue::M(HashMap::from([(
+                "x".to_string(),
+                AttributeValue::S("y".to_string()),
+            )])),
+        ),
+        (
+            "Inner".to_string(),
+            AttributeValue::M(HashMap::from([(
+                "Id".to_string(),
+                AttributeValue::S("id".to_string()),
+            )])),
+        ),
+        ("Count".to_string(), AttributeValue::N("3".to_string())),
+    ]);
+
+    let view = View::from_attribute_value(&map).unwrap();
+    assert_eq!(
+        view,
+        View {
+            name: "foo",
+            tags: vec!["a", "b"],
+            data: b"data",
+            chunks: vec![b"c"],
+            by_name: HashMap::from([("x".to_string(), "y")]),
+            inner: Inner { id: "id" },
+            count: 3,
+        }
+    );
+    assert_eq!(
+        view.name.as_ptr(),
+        map["Name"].as_s().unwrap().as_ptr(),
+        "borrowed from the attributes"
+    );
+    assert_eq!(View::try_from(&map).unwrap(), view);
+    assert_eq!(
+        View::try_from_attribute_value_all_errors(&map).unwrap(),
+        view
+    );
+    assert_eq!(HashMap::from(&view), map);
+    assert_eq!(view.into_item(), map);
+
+    let mut map = map;
+    map.insert("Name".to_string(), AttributeValue::N("1".to_string()));
+    assert_eq!(
+        View::try_from(&map).unwrap_err().to_string(),
+        "type mismatch at `Name`: expected S, found N"
+    );
+}
@@ -2,38 +2,22 @@ use std::collections::HashMap;
 
 use aws_sdk_dynamodb::primitives::Blob;
 use aws_sdk_dynamodb::types::{
-    AttributeDefinition, AttributeValue, GlobalSecondaryIndex, KeySchemaElement, KeyType,
-    LocalSecondaryIndex, Projection, ProjectionType, ProvisionedThroughput, ScalarAttributeType,
+    AttributeDefinition, AttributeValue, BillingMode, GlobalSecondaryIndex, KeySchemaElement,
+    KeyType, LocalSecondaryIndex, Projection, ProjectionType, ScalarAttributeType,
+    SseSpecification, SseType, StreamSpecification, StreamViewType, TableClass,
 };
 use aws_sdk_dynamodb::Client;
 
 use aws_dynamo_derive::Table;
 
-/// ## Compile fail cases
-/// ```compile_fail
-/// #[derive(Table)]
-///     struct Table {
-///         #[aws_dynamo(hash_key)]
-///         hash_key: String,
-///         #[aws_dynamo(hash_key)] // compile fails: only one HashKey is allowed
-///         duplicated_hash_key: String,
-///     }
-///
-///
-/// #[derive(Table)]
-///     struct Table {
-///         #[aws_dynamo(hash_key)]
-///         hash_key: String,
-///         #[aws_dynamo(range_key)]
-///         range_key: u32,
-///         #[aws_dynamo(range_key)]
-///         duplicated_range_key: u32, // compile fails: at most one RangeKey is allowed
-///     }
-
 #[tokio::test]
 async fn test_create_table_and_put_item() {
     #[derive(Table)]
-    #[aws_dynamo(table_name = "AwesomeFooTable")]
+    #[aws_dynamo(
+        table_name = "AwesomeFooTable",
+        billing_mode = "pay_per_request",
+        point_in_time_recovery
+    )]
     pub struct FooTable {
         #[aws_dynamo(range_key)]
         #[aws_dynamo(global_secondary_index(index_name = "gsi1", range_key))]
@@ -313,6 +297,676 @@ async fn test_get_primary_keys() {
         .set_key(Some(primary_key));
 }
 
+#[test]
+fn test_binary_and_number_primary_keys() {
+    #[derive(Debug, Table)]
+    pub struct BlobTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: Blob,
+        #[aws_dynamo(range_key)]
+        range_key: i64,
+    }
+
+    #[derive(Debug, Table)]
+    pub struct BorrowedTable<'a> {
+        #[aws_dynamo(hash_key)]
+        hash_key: &'a [u8],
+    }
+
+    let primary_key = BlobTable::get_primary_keys(BlobTablePrimaryKey {
+        hash_key: Blob::new("hk"),
+        range_key: -1,
+    });
+    assert_eq!(
+        primary_key,
+        HashMap::from([
+            ("HashKey".to_string(), AttributeValue::B(Blob::new("hk"))),
+            ("RangeKey".to_string(), AttributeValue::N("-1".to_string())),
+        ])
+    );
+
+    let primary_key = BorrowedTable::get_primary_keys(BorrowedTablePrimaryKey {
+        hash_key: Blob::new("hk"),
+    });
+    assert_eq!(
+        primary_key,
+        HashMap::from([("HashKey".to_string(), AttributeValue::B(Blob::new("hk")))])
+    );
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct UserId(String);
+
+mod user_id {
+    use super::UserId;
+
+    use aws_dynamo_derive::{AttributeValueType, DynamoConversionError};
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    pub fn to_attribute_value(value: &UserId) -> AttributeValue {
+        AttributeValue::S(format!("USER#{}", value.0))
+    }
+
+    pub fn from_attribute_value(value: &AttributeValue) -> Result<UserId, DynamoConversionError> {
+        match value {
+            AttributeValue::S(s) => Ok(UserId(s.trim_start_matches("USER#").to_string())),
+            value => Err(DynamoConversionError::type_mismatch(
+                AttributeValueType::S,
+                value,
+            )),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_newtype_primary_key() {
+    #[derive(Debug, PartialEq, Table)]
+    pub struct UserTable {
+        #[aws_dynamo(hash_key, with = "user_id", attribute_type = "S")]
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", range_key))]
+        user_id: UserId,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+        group: String,
+        #[aws_dynamo(with = "user_id")]
+        invited_by: UserId,
+    }
+
+    let user = UserTable {
+        user_id: UserId("u1".to_string()),
+        group: "admins".to_string(),
+        invited_by: UserId("u0".to_string()),
+    };
+    let expected_key = HashMap::from([(
+        "UserId".to_string(),
+        AttributeValue::S("USER#u1".to_string()),
+    )]);
+
+    let primary_key = UserTable::get_primary_keys(UserTablePrimaryKey {
+        user_id: UserId("u1".to_string()),
+    });
+    assert_eq!(primary_key, expected_key);
+    assert_eq!(
+        UserTablePrimaryKey::try_from(&primary_key).unwrap(),
+        user.primary_key()
+    );
+
+    let index_key = HashMap::from(UserTableGsi1Key::from(&user));
+    assert_eq!(
+        index_key["UserId"],
+        AttributeValue::S("USER#u1".to_string())
+    );
+
+    let map = HashMap::from(&user);
+    assert_eq!(map["InvitedBy"], AttributeValue::S("USER#u0".to_string()));
+    assert_eq!(UserTable::try_from(&map).unwrap(), user);
+    assert_eq!(UserTable::try_from(map).unwrap(), user);
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    let builder = UserTable::create_table(client.create_table());
+    assert_eq!(
+        builder
+            .get_attribute_definitions()
+            .as_ref()
+            .unwrap()
+            .iter()
+            .find(|definition| definition.attribute_name() == "UserId")
+            .map(|definition| definition.attribute_type()),
+        Some(&ScalarAttributeType::S)
+    );
+
+    let err = UserTablePrimaryKey::try_from(&HashMap::from([(
+        "UserId".to_string(),
+        AttributeValue::N("1".to_string()),
+    )]))
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "type mismatch at `UserId`: expected S, found N"
+    );
+}
+
+#[tokio::test]
+async fn test_primary_key() {
+    #[derive(Debug, Table)]
+    #[aws_dynamo(lenient_numbers)]
+    pub struct KeyTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+        #[aws_dynamo(range_key)]
+        range_key: u32,
+        value: Vec<String>,
+    }
+
+    #[derive(Debug, Table)]
+    pub struct BorrowedKeyTable<'a> {
+        #[aws_dynamo(hash_key)]
+        hash_key: &'a str,
+    }
+
+    let item = KeyTable {
+        hash_key: "hk".to_string(),
+        range_key: 1,
+        value: vec!["v".to_string()],
+    };
+    let primary_key = item.primary_key();
+    assert_eq!(
+        primary_key,
+        KeyTablePrimaryKey {
+            hash_key: "hk".to_string(),
+            range_key: 1,
+        }
+    );
+    assert_eq!(KeyTablePrimaryKey::from(&item), primary_key);
+
+    let key_map: HashMap<String, AttributeValue> = primary_key.clone().into();
+    assert_eq!(
+        key_map,
+        HashMap::from([
+            ("HashKey".to_string(), AttributeValue::S("hk".to_string())),
+            ("RangeKey".to_string(), AttributeValue::N("1".to_string())),
+        ])
+    );
+    assert_eq!(KeyTablePrimaryKey::try_from(&key_map).unwrap(), primary_key);
+
+    // attributes other than the keys are ignored, and numbers follow `lenient_numbers`
+    let mut item_map: HashMap<String, AttributeValue> = (&item).into();
+    item_map.insert("RangeKey".to_string(), AttributeValue::S("1".to_string()));
+    assert_eq!(
+        KeyTablePrimaryKey::try_from(&item_map).unwrap(),
+        primary_key
+    );
+
+    item_map.remove("HashKey");
+    assert_eq!(
+        KeyTablePrimaryKey::try_from(&item_map)
+            .unwrap_err()
+            .to_string(),
+        "missing attribute at `HashKey`"
+    );
+
+    let borrowed = BorrowedKeyTable { hash_key: "hk" };
+    assert_eq!(
+        borrowed.primary_key(),
+        BorrowedKeyTablePrimaryKey {
+            hash_key: "hk".to_string()
+        }
+    );
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    let builder = client
+        .delete_item()
+        .table_name(KeyTable::get_table_name())
+        .set_key(Some(item.primary_key().into()));
+    assert_eq!(builder.get_key().as_ref(), Some(&key_map));
+}
+
+#[test]
+fn test_index_keys() {
+    #[derive(Debug, Table)]
+    pub struct IndexTable {
+        #[aws_dynamo(hash_key)]
+        #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+        hash_key: String,
+        #[aws_dynamo(range_key)]
+        range_key: u32,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+        #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+        gsi_hash_key: String,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi-1.by_date", hash_key))]
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", range_key))]
+        created_at: i64,
+        value: String,
+    }
+
+    let item = IndexTable {
+        hash_key: "hk".to_string(),
+        range_key: 1,
+        gsi_hash_key: "ghk".to_string(),
+        created_at: 100,
+        value: "v".to_string(),
+    };
+
+    // index keys hold the table keys too, as DynamoDB returns them in `LastEvaluatedKey`
+    let gsi_key = IndexTableGsi1Key::from(&item);
+    assert_eq!(
+        gsi_key,
+        IndexTableGsi1Key {
+            hash_key: "hk".to_string(),
+            range_key: 1,
+            gsi_hash_key: "ghk".to_string(),
+            created_at: 100,
+        }
+    );
+    let key_map: HashMap<String, AttributeValue> = gsi_key.clone().into();
+    assert_eq!(
+        key_map,
+        HashMap::from([
+            ("HashKey".to_string(), AttributeValue::S("hk".to_string())),
+            ("RangeKey".to_string(), AttributeValue::N("1".to_string())),
+            (
+                "GsiHashKey".to_string(),
+                AttributeValue::S("ghk".to_string())
+            ),
+            (
+                "CreatedAt".to_string(),
+                AttributeValue::N("100".to_string())
+            ),
+        ])
+    );
+    assert_eq!(IndexTableGsi1Key::try_from(&key_map).unwrap(), gsi_key);
+
+    let item_map: HashMap<String, AttributeValue> = (&item).into();
+    assert_eq!(IndexTableGsi1Key::try_from(&item_map).unwrap(), gsi_key);
+
+    let by_date_key = IndexTableGsi1ByDateKey::from(&item);
+    assert_eq!(
+        by_date_key,
+        IndexTableGsi1ByDateKey {
+            hash_key: "hk".to_string(),
+            range_key: 1,
+            created_at: 100,
+        }
+    );
+
+    // the table hash key is shared by the LSI
+    let lsi_key = IndexTableLsi1Key::from(&item);
+    assert_eq!(
+        lsi_key,
+        IndexTableLsi1Key {
+            hash_key: "hk".to_string(),
+            range_key: 1,
+            gsi_hash_key: "ghk".to_string(),
+        }
+    );
+    let mut lsi_key_map: HashMap<String, AttributeValue> = lsi_key.into();
+    lsi_key_map.remove("GsiHashKey");
+    assert_eq!(
+        IndexTableLsi1Key::try_from(&lsi_key_map)
+            .unwrap_err()
+            .to_string(),
+        "missing attribute at `GsiHashKey`"
+    );
+}
+
+#[tokio::test]
+async fn test_secondary_indexes() {
+    #[derive(Table)]
+    #[aws_dynamo(global_secondary_index(
+        index_name = "gsi1",
+        projection = "include",
+        non_key_attributes = ["Extra"]
+    ))]
+    #[aws_dynamo(local_secondary_index(index_name = "lsi1", projection = "keys_only"))]
+    pub struct IndexedTable {
+        #[aws_dynamo(hash_key)]
+        #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+        hash_key: String,
+        #[aws_dynamo(range_key)]
+        range_key: u32,
+        #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+        created_at: i64,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+        #[aws_dynamo(global_secondary_index(index_name = "gsi2", hash_key))]
+        status: String,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", project))]
+        #[aws_dynamo(global_secondary_index(index_name = "gsi2", project))]
+        title: String,
+        body: String,
+    }
+
+    let key_schema = |name: &str, key_type: KeyType| {
+        KeySchemaElement::builder()
+            .attribute_name(name)
+            .key_type(key_type)
+            .build()
+            .unwrap()
+    };
+
+    let local_secondary_indexes = IndexedTable::get_local_secondary_indexes();
+    assert_eq!(
+        local_secondary_indexes,
+        vec![LocalSecondaryIndex::builder()
+            .index_name("lsi1")
+            .key_schema(key_schema("HashKey", KeyType::Hash))
+            .key_schema(key_schema("CreatedAt", KeyType::Range))
+            .projection(
+                Projection::builder()
+                    .projection_type(ProjectionType::KeysOnly)
+                    .build()
+            )
+            .build()
+            .unwrap()]
+    );
+
+    // `gsi2` projects the fields marked with `project` without a `projection` on top of the struct
+    let global_secondary_indexes = IndexedTable::get_global_secondary_indexes();
+    assert_eq!(
+        global_secondary_indexes,
+        vec![
+            GlobalSecondaryIndex::builder()
+                .index_name("gsi1")
+                .key_schema(key_schema("Status", KeyType::Hash))
+                .projection(
+                    Projection::builder()
+                        .projection_type(ProjectionType::Include)
+                        .non_key_attributes("Extra")
+                        .non_key_attributes("Title")
+                        .build()
+                )
+                .build()
+                .unwrap(),
+            GlobalSecondaryIndex::builder()
+                .index_name("gsi2")
+                .key_schema(key_schema("Status", KeyType::Hash))
+                .projection(
+                    Projection::builder()
+                        .projection_type(ProjectionType::Include)
+                        .non_key_attributes("Title")
+                        .build()
+                )
+                .build()
+                .unwrap(),
+        ]
+    );
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    let builder = IndexedTable::create_table(client.create_table());
+    assert_eq!(
+        builder.get_local_secondary_indexes().as_ref(),
+        Some(&local_secondary_indexes)
+    );
+    assert_eq!(
+        builder.get_global_secondary_indexes().as_ref(),
+        Some(&global_secondary_indexes)
+    );
+}
+
+#[tokio::test]
+async fn test_billing_mode_and_throughput() {
+    #[derive(Table)]
+    #[aws_dynamo(provisioned_throughput(read_capacity_units = 5, write_capacity_units = 2))]
+    #[aws_dynamo(global_secondary_index(
+        index_name = "gsi2",
+        provisioned_throughput(read_capacity_units = 1, write_capacity_units = 1)
+    ))]
+    pub struct ProvisionedTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+        #[aws_dynamo(global_secondary_index(index_name = "gsi2", hash_key))]
+        status: String,
+    }
+
+    #[derive(Table)]
+    #[aws_dynamo(on_demand_throughput(max_read_request_units = 100))]
+    pub struct OnDemandTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+    }
+
+    #[derive(Table)]
+    pub struct DefaultTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+    }
+
+    let throughput = |read: i64, write: i64| {
+        aws_sdk_dynamodb::types::ProvisionedThroughput::builder()
+            .read_capacity_units(read)
+            .write_capacity_units(write)
+            .build()
+            .unwrap()
+    };
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+
+    // GSIs take the throughput of the table unless they declare their own
+    let builder = ProvisionedTable::create_table(client.create_table());
+    assert_eq!(builder.get_billing_mode(), &Some(BillingMode::Provisioned));
+    assert_eq!(
+        builder.get_provisioned_throughput(),
+        &Some(throughput(5, 2))
+    );
+    let global_secondary_indexes = builder.get_global_secondary_indexes().as_ref().unwrap();
+    assert_eq!(
+        global_secondary_indexes[0].provisioned_throughput(),
+        Some(&throughput(5, 2))
+    );
+    assert_eq!(
+        global_secondary_indexes[1].provisioned_throughput(),
+        Some(&throughput(1, 1))
+    );
+
+    let builder = OnDemandTable::create_table(client.create_table());
+    assert_eq!(
+        builder.get_billing_mode(),
+        &Some(BillingMode::PayPerRequest)
+    );
+    assert_eq!(
+        builder.get_on_demand_throughput(),
+        &Some(
+            aws_sdk_dynamodb::types::OnDemandThroughput::builder()
+                .max_read_request_units(100)
+                .build()
+        )
+    );
+
+    // without throughput, the billing mode is left to the caller
+    let builder = DefaultTable::create_table(client.create_table());
+    assert_eq!(builder.get_billing_mode(), &None);
+    assert_eq!(builder.get_provisioned_throughput(), &None);
+}
+
+#[tokio::test]
+async fn test_stream() {
+    #[derive(Table)]
+    #[aws_dynamo(stream = "new_and_old_images")]
+    pub struct StreamTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+    }
+
+    #[derive(Table)]
+    pub struct NoStreamTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+    }
+
+    assert_eq!(
+        StreamTable::STREAM_VIEW_TYPE,
+        Some(StreamViewType::NewAndOldImages)
+    );
+    assert_eq!(NoStreamTable::STREAM_VIEW_TYPE, None);
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+    let builder = StreamTable::create_table(client.create_table());
+    assert_eq!(
+        builder.get_stream_specification(),
+        &Some(
+            StreamSpecification::builder()
+                .stream_enabled(true)
+                .stream_view_type(StreamViewType::NewAndOldImages)
+                .build()
+                .unwrap()
+        )
+    );
+    let builder = NoStreamTable::create_table(client.create_table());
+    assert_eq!(builder.get_stream_specification(), &None);
+}
+
+#[tokio::test]
+async fn test_table_settings() {
+    #[derive(Table)]
+    #[aws_dynamo(
+        table_class = "standard_infrequent_access",
+        deletion_protection,
+        sse(kms_master_key_id = "alias/foo"),
+        point_in_time_recovery
+    )]
+    pub struct ProtectedTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+    }
+
+    #[derive(Table)]
+    #[aws_dynamo(sse)]
+    pub struct ManagedKeyTable {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+    }
+
+    let config = aws_config::load_from_env().await;
+    let client = Client::new(&config);
+
+    let builder = ProtectedTable::create_table(client.create_table());
+    assert_eq!(
+        builder.get_table_class(),
+        &Some(TableClass::StandardInfrequentAccess)
+    );
+    assert_eq!(builder.get_deletion_protection_enabled(), &Some(true));
+    assert_eq!(
+        builder.get_sse_specification(),
+        &Some(
+            SseSpecification::builder()
+                .enabled(true)
+                .sse_type(SseType::Kms)
+                .kms_master_key_id("alias/foo")
+                .build()
+        )
+    );
+
+    let builder = ManagedKeyTable::create_table(client.create_table());
+    assert_eq!(builder.get_table_class(), &None);
+    assert_eq!(builder.get_deletion_protection_enabled(), &None);
+    assert_eq!(
+        builder
+            .get_sse_specification()
+            .as_ref()
+            .and_then(|sse| sse.kms_master_key_id()),
+        None
+    );
+
+    // compiles well, the future is not polled
+    drop(ProtectedTable::enable_point_in_time_recovery(
+        client.update_continuous_backups(),
+    ));
+}
+
+#[test]
+fn test_index_projections() {
+    #[derive(Debug, Table)]
+    #[aws_dynamo(global_secondary_index(
+        index_name = "gsi1",
+        projection = "include",
+        non_key_attributes = ["Extra"]
+    ))]
+    #[aws_dynamo(local_secondary_index(index_name = "lsi1", projection = "keys_only"))]
+    pub struct ProjectedTable {
+        #[aws_dynamo(hash_key)]
+        #[aws_dynamo(local_secondary_index(index_name = "lsi1", hash_key))]
+        hash_key: String,
+        #[aws_dynamo(range_key)]
+        range_key: u32,
+        #[aws_dynamo(local_secondary_index(index_name = "lsi1", range_key))]
+        created_at: i64,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+        #[aws_dynamo(global_secondary_index(index_name = "gsi2", hash_key))]
+        status: String,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", project))]
+        title: String,
+        body: String,
+    }
+
+    #[derive(Table)]
+    #[aws_dynamo(global_secondary_index(index_name = "gsi1", projection = "keys_only"))]
+    pub struct BorrowedProjectedTable<'a> {
+        #[aws_dynamo(hash_key)]
+        hash_key: String,
+        #[aws_dynamo(global_secondary_index(index_name = "gsi1", hash_key))]
+        status: String,
+        body: &'a str,
+    }
+
+    let gsi1_item = HashMap::from([
+        ("HashKey".to_string(), AttributeValue::S("hk".to_string())),
+        ("RangeKey".to_string(), AttributeValue::N("1".to_string())),
+        ("Status".to_string(), AttributeValue::S("open".to_string())),
+        ("Title".to_string(), AttributeValue::S("title".to_string())),
+        ("Extra".to_string(), AttributeValue::S("extra".to_string())),
+    ]);
+    // `CreatedAt` and `Body` are not projected into the index
+    assert_eq!(
+        ProjectedTable::try_from(&gsi1_item)
+            .unwrap_err()
+            .to_string(),
+        "missing attribute at `CreatedAt`"
+    );
+
+    let projection = ProjectedTableGsi1Projection::try_from(&gsi1_item).unwrap();
+    assert_eq!(projection.title, "title");
+    assert_eq!(
+        ProjectedTableGsi1Key::from(&projection),
+        ProjectedTableGsi1Key {
+            hash_key: "hk".to_string(),
+            range_key: 1,
+            status: "open".to_string(),
+        }
+    );
+    let projection_map: HashMap<String, AttributeValue> = projection.into();
+    assert_eq!(projection_map.len(), 4);
+
+    let lsi1_item = HashMap::from([
+        ("HashKey".to_string(), AttributeValue::S("hk".to_string())),
+        ("RangeKey".to_string(), AttributeValue::N("1".to_string())),
+        (
+            "CreatedAt".to_string(),
+            AttributeValue::N("100".to_string()),
+        ),
+    ]);
+    let projection = ProjectedTableLsi1Projection::try_from(lsi1_item).unwrap();
+    assert_eq!(
+        (
+            projection.hash_key.as_str(),
+            projection.range_key,
+            projection.created_at
+        ),
+        ("hk", 1, 100)
+    );
+
+    // `gsi2` projects all attributes, so its items are items of the table
+    let item: ProjectedTableGsi2Projection = ProjectedTable {
+        hash_key: "hk".to_string(),
+        range_key: 1,
+        created_at: 100,
+        status: "open".to_string(),
+        title: "title".to_string(),
+        body: "body".to_string(),
+    };
+    assert_eq!(
+        ProjectedTableGsi2Key::from(&item).status,
+        item.status.as_str()
+    );
+
+    // the borrowed non-key field is not projected, so neither is the lifetime
+    let borrowed_item = HashMap::from([
+        ("HashKey".to_string(), AttributeValue::S("hk".to_string())),
+        ("Status".to_string(), AttributeValue::S("open".to_string())),
+    ]);
+    let projection = BorrowedProjectedTableGsi1Projection::try_from(borrowed_item).unwrap();
+    assert_eq!(
+        BorrowedProjectedTableGsi1Key::from(&projection),
+        BorrowedProjectedTableGsi1Key {
+            hash_key: "hk".to_string(),
+            status: "open".to_string(),
+        }
+    );
+}
+
 // docker run -p 8000:8000 --rm amazon/dynamodb-local
 #[ignore]
 #[tokio::test]
@@ -320,7 +974,11 @@ async fn test_local() {
     std::env::set_var("LOCAL_DYNAMO_URL", "http://localhost:8000");
 
     #[derive(Table)]
-    #[aws_dynamo(table_name = "AwesomeFooTable")]
+    #[aws_dynamo(
+        table_name = "AwesomeFooTable",
+        billing_mode = "pay_per_request",
+        point_in_time_recovery
+    )]
     pub struct FooTable {
         #[aws_dynamo(range_key)]
         #[aws_dynamo(global_secondary_index(index_name = "gsi1", range_key))]
@@ -344,66 +1002,12 @@ async fn test_local() {
     let config = aws_config::load_from_env().await;
     let client = Client::new(&config);
 
-    let lsi_key_schemas = FooTable::get_local_secondary_index_key_schemas();
-    let lsi_builder = LocalSecondaryIndex::builder()
-        .index_name("lsi1")
-        .set_key_schema(Some(lsi_key_schemas.get("lsi1").unwrap().clone()))
-        .projection(
-            Projection::builder()
-                .projection_type(ProjectionType::All)
-                .build(),
-        )
-        .build()
-        .unwrap();
-
-    let gsi_key_schemas = FooTable::get_global_secondary_index_key_schemas();
-    let gsi_builder_1 = GlobalSecondaryIndex::builder()
-        .index_name("gsi1")
-        .set_key_schema(Some(gsi_key_schemas.get("gsi1").unwrap().clone()))
-        .provisioned_throughput(
-            ProvisionedThroughput::builder()
-                .read_capacity_units(1)
-                .write_capacity_units(1)
-                .build()
-                .unwrap(),
-        )
-        .projection(
-            Projection::builder()
-                .projection_type(ProjectionType::All)
-                .build(),
-        )
-        .build()
-        .unwrap();
-    let gsi_builder_2 = GlobalSecondaryIndex::builder()
-        .index_name("gsi2")
-        .set_key_schema(Some(gsi_key_schemas.get("gsi2").unwrap().clone()))
-        .provisioned_throughput(
-            ProvisionedThroughput::builder()
-                .read_capacity_units(1)
-                .write_capacity_units(1)
-                .build()
-                .unwrap(),
-        )
-        .projection(
-            Projection::builder()
-                .projection_type(ProjectionType::All)
-                .build(),
-        )
-        .build()
-        .unwrap();
-
+    // indexes and billing mode are set by `create_table`
     FooTable::create_table(client.create_table())
-        .local_secondary_indexes(lsi_builder)
-        .global_secondary_indexes(gsi_builder_1)
-        .global_secondary_indexes(gsi_builder_2)
-        .provisioned_throughput(
-            ProvisionedThroughput::builder()
-                .read_capacity_units(1)
-                .write_capacity_units(1)
-                .build()
-                .unwrap(),
-        )
         .send()
         .await
         .unwrap();
+    FooTable::enable_point_in_time_recovery(client.update_continuous_backups())
+        .await
+        .unwrap();
 }